@@ -0,0 +1,182 @@
+//! The registry of known answers, kept in `inputs/<year>/answers.toml`.
+//!
+//! Each table is a day and an input name, holding the expected answer to
+//! whichever parts are known for that input:
+//!
+//! ```toml
+//! [day-4.example]
+//! part-1 = 2
+//! part-2 = 4
+//! ```
+
+use crate::Part;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+/// The registry checked in with the repository, which the days' tests read
+/// their answers from.
+#[cfg(any(test, feature = "test-util"))]
+const BUNDLED: &str = include_str!("../../inputs/2022/answers.toml");
+
+/// An expected answer, written as a number or a string.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parts {
+    #[serde(rename = "part-1")]
+    one: Option<Answer>,
+    #[serde(rename = "part-2")]
+    two: Option<Answer>,
+}
+
+/// One expected answer from the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownAnswer<'a> {
+    pub day: u8,
+    pub input: &'a str,
+    pub part: Part,
+    pub answer: &'a Answer,
+}
+
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    days: BTreeMap<u8, BTreeMap<String, Parts>>,
+}
+
+impl KnownAnswers {
+    pub fn get(&self, day: u8, input: &str, part: Part) -> Option<&Answer> {
+        let parts = self.days.get(&day)?.get(input)?;
+        match part {
+            Part::One => parts.one.as_ref(),
+            Part::Two => parts.two.as_ref(),
+        }
+    }
+
+    /// Every known answer, ordered by day, input and part.
+    pub fn iter(&self) -> impl Iterator<Item = KnownAnswer<'_>> {
+        self.days.iter().flat_map(|(&day, inputs)| {
+            inputs.iter().flat_map(move |(input, parts)| {
+                [(Part::One, &parts.one), (Part::Two, &parts.two)]
+                    .into_iter()
+                    .filter_map(move |(part, answer)| {
+                        Some(KnownAnswer {
+                            day,
+                            input,
+                            part,
+                            answer: answer.as_ref()?,
+                        })
+                    })
+            })
+        })
+    }
+}
+
+impl FromStr for KnownAnswers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw: BTreeMap<String, BTreeMap<String, Parts>> =
+            toml::from_str(s).map_err(|err| err.to_string())?;
+
+        let mut days = BTreeMap::new();
+        for (key, inputs) in raw {
+            let day = key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or(format!("`{key}` should look like `day-<number>`"))?;
+            days.insert(day, inputs);
+        }
+
+        Ok(KnownAnswers { days })
+    }
+}
+
+/// The checked in answer for `part` of `day` on `input`, for use in tests.
+///
+/// # Panics
+///
+/// When the registry has no such answer or it doesn't parse as a `T`.
+#[cfg(any(test, feature = "test-util"))]
+pub fn expected<T: FromStr>(day: u8, input: &str, part: Part) -> T {
+    let answer = KnownAnswers::from_str(BUNDLED)
+        .expect("the checked in answers.toml is valid")
+        .get(day, input, part)
+        .unwrap_or_else(|| panic!("no known answer for day {day} {input} part {part}"))
+        .to_string();
+
+    answer
+        .parse()
+        .unwrap_or_else(|_| panic!("known answer `{answer}` has the wrong type"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expected, Answer, KnownAnswers};
+    use crate::Part;
+
+    const REGISTRY: &str = r#"
+[day-5.example]
+part-1 = "CMZ"
+
+[day-4.actual]
+part-1 = 413
+part-2 = 806
+"#;
+
+    #[test]
+    fn test_parse_registry() {
+        let answers: KnownAnswers = REGISTRY.parse().unwrap();
+        assert_eq!(
+            answers.get(5, "example", Part::One),
+            Some(&Answer::Text("CMZ".to_owned()))
+        );
+        assert_eq!(answers.get(5, "example", Part::Two), None);
+        assert_eq!(
+            answers.get(4, "actual", Part::Two),
+            Some(&Answer::Number(806))
+        );
+
+        let listed: Vec<String> = answers
+            .iter()
+            .map(|known| {
+                format!(
+                    "{} {} {} {}",
+                    known.day, known.input, known.part, known.answer
+                )
+            })
+            .collect();
+        assert_eq!(
+            listed,
+            vec!["4 actual 1 413", "4 actual 2 806", "5 example 1 CMZ"]
+        );
+    }
+
+    #[test]
+    fn test_bad_day_key() {
+        assert!("[four.actual]\npart-1 = 1".parse::<KnownAnswers>().is_err());
+        assert!("[day-4.actual]\npart-3 = 1"
+            .parse::<KnownAnswers>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_expected() {
+        assert_eq!(expected::<usize>(4, "actual", Part::One), 413);
+        assert_eq!(expected::<String>(5, "actual", Part::Two), "HZFZCCWWV");
+    }
+}
@@ -0,0 +1,111 @@
+//! What goes wrong when a solver is handed input it can't make sense of.
+
+use crate::Part;
+use std::fmt::Display;
+
+/// A solver failing on its input, pointing at the offending line where it can.
+///
+/// Lines and columns count from 1, columns in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input isn't in the shape the day expects.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input parsed but following it goes wrong, e.g. taking a crate off
+    /// an empty stack. Some failures can't be put down to any one line.
+    Solve {
+        day: u8,
+        line: Option<usize>,
+        message: String,
+    },
+    /// The input has no answer for `part`, e.g. day 6 never finding its marker.
+    NoAnswer { day: u8, part: Part },
+}
+
+impl Error {
+    pub fn parse(day: u8, line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            day,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(f, "day {day} input line {line} column {column}: {message}"),
+            Error::Solve {
+                day,
+                line: Some(line),
+                message,
+            } => write!(f, "day {day} input line {line}: {message}"),
+            Error::Solve {
+                day,
+                line: None,
+                message,
+            } => write!(f, "day {day}: {message}"),
+            Error::NoAnswer { day, part } => {
+                write!(f, "day {day} part {part} has no answer for this input")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The line and column where `rest`, the unparsed tail of `input`, starts.
+///
+/// Handy for turning what a parser left over into a position.
+pub fn position(input: &str, rest: &str) -> (usize, usize) {
+    let parsed = &input[..input.len() - rest.len()];
+    let line_start = parsed.rfind('\n').map_or(0, |newline| newline + 1);
+
+    (
+        parsed.matches('\n').count() + 1,
+        parsed[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{position, Error};
+    use crate::Part;
+
+    #[test]
+    fn test_position() {
+        let input = "ab\ncdé\nfg";
+        assert_eq!(position(input, input), (1, 1));
+        assert_eq!(position(input, "b\ncdé\nfg"), (1, 2));
+        assert_eq!(position(input, "cdé\nfg"), (2, 1));
+        assert_eq!(position(input, "\nfg"), (2, 4));
+        assert_eq!(position(input, ""), (3, 3));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Error::parse(4, 3, 5, "`x` isn't a section id").to_string(),
+            "day 4 input line 3 column 5: `x` isn't a section id"
+        );
+        assert_eq!(
+            Error::NoAnswer {
+                day: 6,
+                part: Part::Two
+            }
+            .to_string(),
+            "day 6 part 2 has no answer for this input"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Pieces every day's solver shares.
+
+pub mod answers;
+pub mod error;
+pub mod table;
+
+pub use error::Error;
+
+use std::fmt::Display;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            other => Err(other),
+        }
+    }
+}
+
+/// A day's puzzle: parse the input once, then answer either part from it.
+///
+/// Bad input comes back as an [`Error`] rather than a panic, and a part with
+/// nothing to find, e.g. day 6 never finding its marker, as [`Error::NoAnswer`].
+pub trait Solution {
+    /// The day of the calendar this solves.
+    const DAY: u8;
+
+    /// What [`Solution::parse`] turns the raw input into, free to borrow from it.
+    type Parsed<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, Error>;
+    fn part_one(parsed: &Self::Parsed<'_>) -> Result<Self::PartOne, Error>;
+    fn part_two(parsed: &Self::Parsed<'_>) -> Result<Self::PartTwo, Error>;
+}
+
+/// Parses `input` and runs a single `part` of `S`, rendering the answer.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, Error> {
+    let parsed = S::parse(input)?;
+    match part {
+        Part::One => S::part_one(&parsed).map(|answer| answer.to_string()),
+        Part::Two => S::part_two(&parsed).map(|answer| answer.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{solve, Error, Part, Solution};
+
+    struct Words;
+
+    impl Solution for Words {
+        const DAY: u8 = 0;
+        type Parsed<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> Result<Vec<&str>, Error> {
+            match input.find(char::is_numeric) {
+                Some(at) => Err(Error::parse(0, 1, at + 1, "numbers aren't words")),
+                None => Ok(input.split_whitespace().collect()),
+            }
+        }
+
+        fn part_one(words: &Vec<&str>) -> Result<usize, Error> {
+            Ok(words.len())
+        }
+
+        fn part_two(words: &Vec<&str>) -> Result<String, Error> {
+            words
+                .iter()
+                .max_by_key(|word| word.len())
+                .map(|word| word.to_string())
+                .ok_or(Error::NoAnswer {
+                    day: 0,
+                    part: Part::Two,
+                })
+        }
+    }
+
+    #[test]
+    fn test_solve_parts() {
+        assert_eq!(solve::<Words>("a bb ccc", Part::One), Ok("3".to_string()));
+        assert_eq!(solve::<Words>("a bb ccc", Part::Two), Ok("ccc".to_string()));
+        assert_eq!(
+            solve::<Words>("", Part::Two),
+            Err(Error::NoAnswer {
+                day: 0,
+                part: Part::Two
+            })
+        );
+        assert_eq!(
+            solve::<Words>("a b2", Part::One),
+            Err(Error::parse(0, 1, 4, "numbers aren't words"))
+        );
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err(3));
+    }
+}
@@ -0,0 +1,100 @@
+//! Plain text tables for the commands that print one.
+
+use std::fmt::Write;
+
+/// Which side of its column a cell keeps to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Alignment {
+    #[default]
+    Left,
+    /// For numbers, so their digits line up.
+    Right,
+}
+
+/// Lays `rows` out under `header` in left aligned columns, two spaces apart
+/// and with nothing trailing on any line.
+pub fn align<R: AsRef<[String]>>(header: &[&str], rows: &[R]) -> String {
+    align_columns(header, &[], rows)
+}
+
+/// [`align`] with each column kept to the side `alignments` gives it, left
+/// for any past its end. An empty `header` leaves the header line out.
+pub fn align_columns<R: AsRef<[String]>>(
+    header: &[&str],
+    alignments: &[Alignment],
+    rows: &[R],
+) -> String {
+    let header: Vec<String> = header.iter().map(|cell| cell.to_string()).collect();
+    let rows: Vec<&[String]> = std::iter::once(header.as_slice())
+        .filter(|header| !header.is_empty())
+        .chain(rows.iter().map(AsRef::as_ref))
+        .collect();
+
+    let mut widths: Vec<usize> = Vec::new();
+    for row in &rows {
+        widths.resize(widths.len().max(row.len()), 0);
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                match alignments.get(column).copied().unwrap_or_default() {
+                    Alignment::Left => format!("{cell:<width$}"),
+                    Alignment::Right => format!("{cell:>width$}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(out, "{}", line.trim_end()).unwrap();
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{align, align_columns, Alignment};
+
+    #[test]
+    fn test_align() {
+        let rows = [
+            ["1".to_owned(), "example".to_owned(), String::new()],
+            ["10".to_owned(), "a".to_owned(), "ok".to_owned()],
+        ];
+        assert_eq!(
+            align(&["day", "input", "result"], &rows),
+            "day  input    result\n\
+             1    example\n\
+             10   a        ok\n"
+        );
+        assert_eq!(align::<Vec<String>>(&["only"], &[]), "only\n");
+    }
+
+    #[test]
+    fn test_align_columns() {
+        let rows = [
+            vec!["items".to_owned(), "1".to_owned(), "2.5".to_owned()],
+            vec!["calories".to_owned(), "4000".to_owned(), "γ".to_owned()],
+        ];
+        assert_eq!(
+            align_columns(&["", "min"], &[Alignment::Left, Alignment::Right], &rows),
+            [
+                "           min\n",
+                "items        1  2.5\n",
+                "calories  4000  γ\n",
+            ]
+            .concat()
+        );
+        assert_eq!(
+            align_columns(&[], &[Alignment::Right], &rows),
+            ["   items  1     2.5\n", "calories  4000  γ\n"].concat()
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Timing each day's parse step and parts.
+//!
+//! A run can be saved as JSON and handed back as the baseline of a later run,
+//! which then flags every step whose median got slower by more than a
+//! threshold.
+
+use crate::with_solution;
+use aoc_core::{
+    table::{align_columns, Alignment},
+    Error, Solution,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Summary of a batch of timings, all in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub min: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// When there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "can't summarise zero samples");
+
+        let mut nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / count;
+        let middle = nanos.len() / 2;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2.0
+        } else {
+            nanos[middle]
+        };
+
+        Stats {
+            mean,
+            median,
+            min: nanos[0],
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// A step of a solution that gets timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::PartOne => write!(f, "part 1"),
+            Step::PartTwo => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl DayBench {
+    pub fn steps(&self) -> [(Step, &Stats); 3] {
+        [
+            (Step::Parse, &self.parse),
+            (Step::PartOne, &self.part_one),
+            (Step::PartTwo, &self.part_two),
+        ]
+    }
+}
+
+/// Every day benchmarked in one run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+fn time<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing `input` and each part of `S` on it, `iterations` times each.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<DayBench, Error> {
+    let parsed = S::parse(input)?;
+    let parse = time(iterations, || S::parse(black_box(input)));
+
+    let part_one = time(iterations, || S::part_one(black_box(&parsed)));
+    let part_two = time(iterations, || S::part_two(black_box(&parsed)));
+
+    Ok(DayBench {
+        day: S::DAY,
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+/// [`bench`] for `day`, or `None` when it isn't solved.
+pub fn bench_day(day: u8, input: &str, iterations: usize) -> Option<Result<DayBench, Error>> {
+    with_solution!(day, S => bench::<S>(input, iterations))
+}
+
+/// A step that got slower than its baseline allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub step: Step,
+    pub before: Stats,
+    pub after: Stats,
+}
+
+impl Regression {
+    /// How much slower the median got, `0.5` being half as slow again.
+    pub fn slowdown(&self) -> f64 {
+        self.after.median / self.before.median - 1.0
+    }
+}
+
+/// The steps in `current` whose median is more than `threshold` (`0.1` being
+/// 10%) slower than in `baseline`. Days missing from either are skipped.
+pub fn regressions(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    current
+        .days
+        .iter()
+        .filter_map(|after| {
+            let before = baseline
+                .days
+                .iter()
+                .find(|before| before.day == after.day)?;
+            let day = after.day;
+            Some(before.steps().into_iter().zip(after.steps()).filter_map(
+                move |((step, before), (_, after))| {
+                    (after.median > before.median * (1.0 + threshold)).then_some(Regression {
+                        day,
+                        step,
+                        before: *before,
+                        after: *after,
+                    })
+                },
+            ))
+        })
+        .flatten()
+        .collect()
+}
+
+fn nanos(nanos: f64) -> String {
+    format!("{:?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Lays a report out as a table, one row per step.
+pub fn table(report: &Report) -> String {
+    let rows: Vec<[String; 6]> = report
+        .days
+        .iter()
+        .flat_map(|day| {
+            day.steps().map(|(step, stats)| {
+                [
+                    day.day.to_string(),
+                    step.to_string(),
+                    nanos(stats.mean),
+                    nanos(stats.median),
+                    nanos(stats.min),
+                    nanos(stats.stddev),
+                ]
+            })
+        })
+        .collect();
+
+    align_columns(
+        &["day", "step", "mean", "median", "min", "stddev"],
+        &[
+            Alignment::Left,
+            Alignment::Left,
+            Alignment::Right,
+            Alignment::Right,
+            Alignment::Right,
+            Alignment::Right,
+        ],
+        &rows,
+    )
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} {}: median {} -> {} ({:+.1}%)",
+            self.day,
+            self.step,
+            nanos(self.before.median),
+            nanos(self.after.median),
+            self.slowdown() * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bench_day, regressions, table, DayBench, Report, Stats, Step};
+    use std::time::Duration;
+
+    fn stats(median: f64) -> Stats {
+        Stats {
+            mean: median,
+            median,
+            min: median,
+            stddev: 0.0,
+        }
+    }
+
+    fn day(day: u8, parse: f64, part_one: f64, part_two: f64) -> DayBench {
+        DayBench {
+            day,
+            parse: stats(parse),
+            part_one: stats(part_one),
+            part_two: stats(part_two),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.mean, 2.5);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.stddev, 1.25_f64.sqrt());
+
+        let odd = Stats::from_samples(&[7, 1, 100].map(Duration::from_nanos));
+        assert_eq!(odd.median, 7.0);
+    }
+
+    #[test]
+    fn test_table() {
+        let report = Report {
+            iterations: 1,
+            days: vec![day(4, 1_500_000.0, 40_000.0, 900.0)],
+        };
+        assert_eq!(
+            table(&report),
+            "day  step     mean  median    min  stddev\n\
+             4    parse   1.5ms   1.5ms  1.5ms     0ns\n\
+             4    part 1   40µs    40µs   40µs     0ns\n\
+             4    part 2  900ns   900ns  900ns     0ns\n"
+        );
+    }
+
+    #[test]
+    fn test_bench_day() {
+        let bench = bench_day(4, "2-4,6-8\n2-8,3-7", 3).unwrap().unwrap();
+        assert_eq!(bench.day, 4);
+        assert!(bench.parse.min <= bench.parse.median);
+        assert!(bench_day(2, "", 3).is_none());
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = Report {
+            iterations: 10,
+            days: vec![day(4, 100.0, 100.0, 100.0), day(5, 100.0, 100.0, 100.0)],
+        };
+        let current = Report {
+            iterations: 10,
+            days: vec![day(4, 105.0, 150.0, 50.0), day(6, 1000.0, 1000.0, 1000.0)],
+        };
+
+        let found = regressions(&baseline, &current, 0.1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].day, 4);
+        assert_eq!(found[0].step, Step::PartOne);
+        assert_eq!(found[0].slowdown(), 0.5);
+
+        let round_trip: Report =
+            serde_json::from_str(&serde_json::to_string(&current).unwrap()).unwrap();
+        assert_eq!(round_trip, current);
+    }
+}
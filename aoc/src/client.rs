@@ -0,0 +1,412 @@
+//! Talking to adventofcode.com.
+//!
+//! All the HTTP goes through [`Http`] so tests can stand in for the site, the
+//! real thing being [`Curl`].
+
+use crate::{input::Inputs, submit::Reply};
+use aoc_core::Part;
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/LiamKearn/aoc";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used to reach the site.
+pub trait Http {
+    /// Sends a GET to `url` logged in with the `session` cookie.
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError>;
+
+    /// Posts `form` to `url` as `application/x-www-form-urlencoded`.
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError>;
+}
+
+/// Does the requests with the `curl` binary, keeping TLS out of the build.
+///
+/// The session is handed over on stdin so it never shows up in `ps`.
+pub struct Curl;
+
+impl Curl {
+    fn request(&self, config: &str) -> Result<Response, ClientError> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| ClientError::Transport(format!("can't run curl: {err}")))?;
+
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(config.as_bytes())?;
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(ClientError::Transport(
+                String::from_utf8_lossy(&output.stderr).trim().to_owned(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| ClientError::Transport("curl gave no status".to_owned()))?;
+        let status = status
+            .parse()
+            .map_err(|_| ClientError::Transport(format!("curl gave a bad status `{status}`")))?;
+
+        Ok(Response {
+            status,
+            body: body.to_owned(),
+        })
+    }
+}
+
+impl Http for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<Response, ClientError> {
+        self.request(&config(url, session)?)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ClientError> {
+        let mut config = config(url, session)?;
+        for (key, value) in form {
+            let data = quoted("form field", &format!("{key}={value}"))?;
+            config.push_str(&format!("data-urlencode = {data}\n"));
+        }
+
+        self.request(&config)
+    }
+}
+
+/// The lines of a curl config logging in to `url` with `session`.
+fn config(url: &str, session: &str) -> Result<String, ClientError> {
+    Ok(format!(
+        "url = {}\ncookie = {}\nuser-agent = \"{USER_AGENT}\"\n",
+        quoted("url", url)?,
+        quoted("session", &format!("session={session}"))?,
+    ))
+}
+
+/// `value` as a quoted curl config string. A line break would end the
+/// directive early and start another, so there's no way to pass one.
+fn quoted(what: &str, value: &str) -> Result<String, ClientError> {
+    if value.contains(['\n', '\r']) {
+        return Err(ClientError::Transport(format!(
+            "the {what} can't have a line break in it"
+        )));
+    }
+
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    Transport(String),
+    Status {
+        status: u16,
+        body: String,
+    },
+    /// The site answered with a page we don't know how to read.
+    Unrecognised(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Io(err) => write!(f, "{err}"),
+            ClientError::Transport(message) => write!(f, "{message}"),
+            ClientError::Status { status, body } => {
+                write!(f, "the site answered {status}: {}", body.trim())
+            }
+            ClientError::Unrecognised(body) => {
+                write!(f, "can't make sense of the site's answer: {}", body.trim())
+            }
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(value: io::Error) -> Self {
+        ClientError::Io(value)
+    }
+}
+
+/// A logged in session against the site.
+pub struct Client<H> {
+    http: H,
+    base_url: String,
+    session: String,
+}
+
+impl<H: Http> Client<H> {
+    pub fn new(http: H, base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+        }
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    /// Downloads the account's puzzle input for `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .http
+            .get(&format!("{}/input", self.day_url(year, day)), &self.session)?;
+
+        ok_body(response)
+    }
+
+    /// Submits `answer` for `part` of `day`, reading the reply off the page.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Reply, ClientError> {
+        let response = self.http.post_form(
+            &format!("{}/answer", self.day_url(year, day)),
+            &self.session,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+
+        let body = ok_body(response)?;
+        Reply::parse(&body).ok_or(ClientError::Unrecognised(body))
+    }
+}
+
+fn ok_body(response: Response) -> Result<String, ClientError> {
+    match response.status {
+        200 => Ok(response.body),
+        status => Err(ClientError::Status {
+            status,
+            body: response.body,
+        }),
+    }
+}
+
+/// Where the puzzle input ended up and whether it had to be downloaded.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub downloaded: bool,
+}
+
+/// Makes sure the actual input for `day` is in `inputs`, only downloading it
+/// when there isn't a copy there yet.
+pub fn fetch_input<H: Http>(
+    client: &Client<H>,
+    inputs: &Inputs,
+    day: u8,
+) -> Result<Fetched, ClientError> {
+    let path = inputs.path(day, crate::input::ACTUAL);
+    if path.exists() {
+        return Ok(Fetched {
+            path,
+            downloaded: false,
+        });
+    }
+
+    let input = client.input(inputs.year(), day)?;
+    let day_dir = inputs.day_dir(day);
+    std::fs::create_dir_all(&day_dir)?;
+    // Written beside the input and renamed over it, so an interrupted write
+    // never leaves a partial file to be taken for the cached copy.
+    let partial = day_dir.join(format!(".actual.txt.{}.part", std::process::id()));
+    if let Err(err) =
+        std::fs::write(&partial, input).and_then(|()| std::fs::rename(&partial, &path))
+    {
+        let _ = std::fs::remove_file(&partial);
+        return Err(err.into());
+    }
+
+    Ok(Fetched {
+        path,
+        downloaded: true,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{config, fetch_input, Client, ClientError, Curl, Http, Response};
+    use crate::input::Inputs;
+    use std::{
+        cell::RefCell,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        process::Command,
+        thread,
+    };
+
+    /// Stands in for the site, answering every request with `response`.
+    pub(crate) struct FakeSite {
+        pub(crate) response: Response,
+        pub(crate) requests: RefCell<Vec<String>>,
+    }
+
+    impl FakeSite {
+        pub(crate) fn new(status: u16, body: &str) -> Self {
+            FakeSite {
+                response: Response {
+                    status,
+                    body: body.to_owned(),
+                },
+                requests: RefCell::default(),
+            }
+        }
+    }
+
+    impl Http for &FakeSite {
+        fn get(&self, url: &str, session: &str) -> Result<Response, ClientError> {
+            assert_eq!(session, "cookie");
+            self.requests.borrow_mut().push(format!("GET {url}"));
+            Ok(self.response.clone())
+        }
+
+        fn post_form(
+            &self,
+            url: &str,
+            session: &str,
+            form: &[(&str, &str)],
+        ) -> Result<Response, ClientError> {
+            assert_eq!(session, "cookie");
+            let form: Vec<String> = form.iter().map(|(k, v)| format!("{k}={v}")).collect();
+            self.requests
+                .borrow_mut()
+                .push(format!("POST {url} {}", form.join("&")));
+            Ok(self.response.clone())
+        }
+    }
+
+    #[test]
+    fn test_fetch_downloads_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs = Inputs::new(dir.path(), 2022);
+        let site = FakeSite::new(200, "1-2,3-4\n");
+        let client = Client::new(&site, "http://site/", "cookie");
+
+        let fetched = fetch_input(&client, &inputs, 4).unwrap();
+        assert!(fetched.downloaded);
+        assert_eq!(fetched.path, dir.path().join("2022/day-4/actual.txt"));
+        assert_eq!(inputs.read(4, "actual").unwrap(), "1-2,3-4\n");
+        let files: Vec<_> = std::fs::read_dir(inputs.day_dir(4))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(files, vec!["actual.txt"]);
+
+        let cached = fetch_input(&client, &inputs, 4).unwrap();
+        assert!(!cached.downloaded);
+        assert_eq!(
+            *site.requests.borrow(),
+            vec!["GET http://site/2022/day/4/input"]
+        );
+    }
+
+    #[test]
+    fn test_fetch_failure_leaves_no_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs = Inputs::new(dir.path(), 2022);
+        let site = FakeSite::new(400, "Puzzle inputs differ by user.");
+        let client = Client::new(&site, "http://site", "cookie");
+
+        let err = fetch_input(&client, &inputs, 5).unwrap_err();
+        assert!(matches!(err, ClientError::Status { status: 400, .. }));
+        assert!(!inputs.path(5, "actual").exists());
+    }
+
+    #[test]
+    fn test_curl_config_quoting() {
+        assert_eq!(
+            config("http://site/?a=\"b\"", "c\\d").unwrap(),
+            "url = \"http://site/?a=\\\"b\\\"\"\n\
+             cookie = \"session=c\\\\d\"\n\
+             user-agent = \"github.com/LiamKearn/aoc\"\n"
+        );
+
+        // Refused before curl ever runs, so nothing can be slipped in after.
+        let err = Curl
+            .get("http://site", "cookie\"\nurl = \"http://elsewhere")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "the session can't have a line break in it");
+        let err = Curl
+            .post_form("http://site", "cookie", &[("answer", "1\r\n2")])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the form field can't have a line break in it"
+        );
+    }
+
+    #[test]
+    fn test_curl_against_local_server() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            eprintln!("curl isn't installed, skipping");
+            return;
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            for line in BufReader::new(&stream).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push(line);
+            }
+
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\nabcd\n\n",
+                )
+                .unwrap();
+            request
+        });
+
+        let client = Client::new(Curl, format!("http://{addr}"), "cookie");
+        assert_eq!(client.input(2022, 6).unwrap(), "abcd\n\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/6/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|header| header == "Cookie: session=cookie"));
+    }
+}
@@ -0,0 +1,214 @@
+//! Where puzzle input is read from.
+//!
+//! Inputs live on disk as `<dir>/<year>/day-<day>/<name>.txt`, `actual` being
+//! the account's puzzle input and `example`/`example-N` the ones from the
+//! puzzle text. A path or stdin can stand in for any of them.
+
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// The year every day in this workspace belongs to.
+pub const YEAR: u16 = 2022;
+
+/// The name of the account's own puzzle input.
+pub const ACTUAL: &str = "actual";
+
+/// A directory of inputs laid out per year and per day.
+#[derive(Debug, Clone)]
+pub struct Inputs {
+    dir: PathBuf,
+    year: u16,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>, year: u16) -> Self {
+        Inputs {
+            dir: dir.into(),
+            year,
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The known answer registry for the year.
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join(self.year.to_string()).join("answers.toml")
+    }
+
+    /// The directory holding every input for `day`.
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir
+            .join(self.year.to_string())
+            .join(format!("day-{day}"))
+    }
+
+    pub fn path(&self, day: u8, name: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{name}.txt"))
+    }
+
+    pub fn read(&self, day: u8, name: &str) -> io::Result<String> {
+        read_file(&self.path(day, name))
+    }
+
+    /// The name of every input there is for `day`, sorted.
+    pub fn names(&self, day: u8) -> io::Result<Vec<String>> {
+        let dir = self.day_dir(day);
+        let mut names = Vec::new();
+        for entry in std::fs::read_dir(&dir).map_err(|err| with_path(&dir, err))? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_owned());
+                }
+            }
+        }
+
+        names.sort();
+        Ok(names)
+    }
+}
+
+/// Something to read a single input from.
+#[derive(Debug, Clone)]
+pub enum Source {
+    /// An input by name from an [`Inputs`] directory.
+    Named {
+        inputs: Inputs,
+        day: u8,
+        name: String,
+    },
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Picks the source for a command line `--input`, where `-` means stdin,
+    /// falling back to the input called `name` when no path was given.
+    pub fn from_args(inputs: &Inputs, day: u8, path: Option<PathBuf>, name: &str) -> Self {
+        match path {
+            Some(path) if path.as_os_str() == "-" => Source::Stdin,
+            Some(path) => Source::Path(path),
+            None => Source::Named {
+                inputs: inputs.clone(),
+                day,
+                name: name.to_owned(),
+            },
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Source::Named { inputs, day, name } => inputs.read(*day, name),
+            Source::Path(path) => read_file(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the input for reading bit by bit rather than all at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Source::Named { inputs, day, name } => open_file(&inputs.path(*day, name)),
+            Source::Path(path) => open_file(path),
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Named { inputs, day, name } => {
+                write!(f, "{}", inputs.path(*day, name).display())
+            }
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+fn with_path(path: &Path, err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path).map_err(|err| with_path(path, err))
+}
+
+fn open_file(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let file = File::open(path).map_err(|err| with_path(path, err))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{Inputs, Source, YEAR};
+    use std::{
+        io::Read,
+        path::{Path, PathBuf},
+    };
+
+    /// The inputs checked in alongside the workspace.
+    pub(crate) fn inputs() -> Inputs {
+        Inputs::new(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs"),
+            YEAR,
+        )
+    }
+
+    #[test]
+    fn test_named_path() {
+        let inputs = Inputs::new("inputs", 2022);
+        assert_eq!(
+            inputs.path(4, "example"),
+            PathBuf::from("inputs/2022/day-4/example.txt")
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(inputs().names(4).unwrap(), vec!["actual", "example"]);
+        assert!(inputs().names(25).is_err());
+    }
+
+    #[test]
+    fn test_read_named() {
+        let input = inputs().read(4, "example").unwrap();
+        assert!(input.starts_with("2-4,6-8\n"));
+    }
+
+    #[test]
+    fn test_source_from_args() {
+        let inputs = inputs();
+        assert!(matches!(
+            Source::from_args(&inputs, 6, Some("-".into()), "actual"),
+            Source::Stdin
+        ));
+        assert!(matches!(
+            Source::from_args(&inputs, 6, Some("a.txt".into()), "actual"),
+            Source::Path(_)
+        ));
+
+        let named = Source::from_args(&inputs, 6, None, "example-5");
+        assert_eq!(named.read().unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        let mut opened = String::new();
+        named.open().unwrap().read_to_string(&mut opened).unwrap();
+        assert_eq!(opened, "mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    }
+
+    #[test]
+    fn test_missing_input_names_path() {
+        let err = inputs().read(25, "actual").unwrap_err();
+        assert!(err.to_string().contains("day-25/actual.txt"));
+    }
+}
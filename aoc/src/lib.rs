@@ -0,0 +1,104 @@
+//! Every day's solvers, linkable from one place.
+//!
+//! Each day is its own library crate in the workspace; this one re-exports
+//! them and dispatches on a day number, so the `aoc` command and any other
+//! tooling only need to depend on it.
+
+pub mod bench;
+pub mod client;
+pub mod input;
+pub mod parity;
+pub mod submit;
+pub mod verify;
+
+pub use aoc_core::{answers::KnownAnswers, solve, Error, Part, Solution};
+
+pub use day_1::{self, Day1};
+pub use day_3::{self, Day3};
+pub use day_4::{self, Day4};
+pub use day_5::{self, Day5};
+pub use day_6::{self, Day6};
+
+/// Days with a solution in this workspace, in calendar order.
+pub const DAYS: [u8; 5] = [Day1::DAY, Day3::DAY, Day4::DAY, Day5::DAY, Day6::DAY];
+
+/// Runs `$body` with `$solution` bound to the [`Solution`] for `$day`, giving
+/// `Some` of the result, or `None` when that day hasn't been solved.
+#[macro_export]
+macro_rules! with_solution {
+    ($day:expr, $solution:ident => $body:expr) => {
+        match $day {
+            1 => {
+                type $solution = $crate::Day1;
+                Some($body)
+            }
+            3 => {
+                type $solution = $crate::Day3;
+                Some($body)
+            }
+            4 => {
+                type $solution = $crate::Day4;
+                Some($body)
+            }
+            5 => {
+                type $solution = $crate::Day5;
+                Some($body)
+            }
+            6 => {
+                type $solution = $crate::Day6;
+                Some($body)
+            }
+            _ => None,
+        }
+    };
+}
+
+/// Solves one `part` of `day` for `input`, or `None` when the day isn't
+/// solved here.
+pub fn solve_day(day: u8, part: Part, input: &str) -> Option<Result<String, Error>> {
+    with_solution!(day, S => solve::<S>(input, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{solve_day, Error, Part};
+
+    #[test]
+    fn test_unsolved_day() {
+        assert_eq!(solve_day(2, Part::One, ""), None);
+    }
+
+    #[test]
+    fn test_bad_input_is_an_error() {
+        let error = |day, input| {
+            solve_day(day, Part::One, input)
+                .unwrap()
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(1, "1000\n2000\n\n3000\nlots"),
+            "day 1 input line 5 column 1: `lots` isn't a calorie count: invalid digit found in string"
+        );
+        assert_eq!(
+            error(3, "vJrwpWtwJgWr\nhcsFMMfF1FhFp"),
+            "day 3 input line 2 column 9: `1` isn't an item type"
+        );
+        assert_eq!(
+            error(4, "2-4,6-8\n2-3;4-5"),
+            "day 4 input line 2 column 3: `3;4` isn't a section id: invalid digit found in string"
+        );
+        assert_eq!(
+            error(5, "[A]\n 1 \nmove 1 from 1 to 1"),
+            "day 5 input line 3 column 19: expected a blank line between the stacks and the moves"
+        );
+        assert_eq!(
+            solve_day(6, Part::One, "aaaa"),
+            Some(Err(Error::NoAnswer {
+                day: 6,
+                part: Part::One
+            }))
+        );
+    }
+}
@@ -0,0 +1,763 @@
+use aoc::{
+    bench::{self, bench_day, regressions, Report},
+    client::{fetch_input, Client, Curl, BASE_URL},
+    day_1::{self, sum_top_k, top_k_streaming, Elves, Width},
+    day_3::{
+        self, badge_priorities,
+        explain::{self, explain_part_1, explain_part_2, Row},
+        validate::validate,
+        Day3,
+    },
+    day_4::{classify, Day4},
+    day_5::{self, enact_move_part_1, enact_move_part_2, solve_animated, Stage},
+    input::{Inputs, Source, ACTUAL, YEAR},
+    parity::{self, compare},
+    solve_day,
+    submit::{submit, Verdict},
+    verify::{table, verify},
+    Error, KnownAnswers, Part, Solution, DAYS,
+};
+use aoc_core::table::{align_columns, Alignment};
+use clap::{builder::RangedU64ValueParser, Args, Parser, Subcommand, ValueEnum};
+use std::{
+    io::BufRead,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    /// Directory holding the puzzle inputs, as `<year>/day-<day>/<name>.txt`.
+    #[arg(long, global = true, env = "AOC_INPUTS", default_value = "inputs")]
+    inputs: PathBuf,
+    #[arg(long, global = true, default_value_t = YEAR)]
+    year: u16,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Which input a command reads.
+#[derive(Args)]
+struct InputArgs {
+    /// Read this file instead, `-` for stdin.
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Name of the input in the inputs directory.
+    #[arg(long, default_value = ACTUAL)]
+    name: String,
+}
+
+impl InputArgs {
+    fn source(&self, inputs: &Inputs, day: u8) -> Source {
+        Source::from_args(inputs, day, self.input.clone(), &self.name)
+    }
+
+    fn read(&self, inputs: &Inputs, day: u8) -> Result<String, String> {
+        self.source(inputs, day)
+            .read()
+            .map_err(|err| format!("can't read input: {err}"))
+    }
+
+    fn open(&self, inputs: &Inputs, day: u8) -> Result<Box<dyn BufRead>, String> {
+        self.source(inputs, day)
+            .open()
+            .map_err(|err| format!("can't read input: {err}"))
+    }
+}
+
+/// How to reach adventofcode.com.
+#[derive(Args)]
+struct SiteArgs {
+    /// The `session` cookie of a logged in browser.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+    #[arg(long, env = "AOC_BASE_URL", default_value = BASE_URL, hide = true)]
+    base_url: String,
+}
+
+impl SiteArgs {
+    fn client(&self) -> Client<Curl> {
+        Client::new(Curl, &self.base_url, &self.session)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day's puzzle, printing each answer and how long it took.
+    Run {
+        #[arg(long)]
+        day: u8,
+        /// Only solve this part, otherwise both.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Check every solver against the known answers, printing a table.
+    Verify {
+        /// The registry to check against, by default `answers.toml` in the
+        /// year's inputs.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time each day's parse step and parts over many runs.
+    Bench {
+        /// Only benchmark this day, otherwise every solved day.
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Name of the input to run on for each day.
+        #[arg(long, default_value = ACTUAL)]
+        name: String,
+        /// Save the results as JSON.
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Results of an earlier run to flag regressions against.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How much slower a median may get before it's a regression.
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+    },
+    /// Diff another implementation's answers against the Rust solver's on
+    /// the same inputs, e.g. `aoc parity --day 1 --part 1 -- php day-1/in.php`.
+    ///
+    /// The command gets each input on stdin and prints its answer.
+    Parity {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Only compare on this input, once per value, otherwise every input
+        /// the day has.
+        #[arg(long)]
+        name: Vec<String>,
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+    /// Download a day's puzzle input into the inputs directory, unless it's
+    /// already there.
+    Fetch {
+        #[arg(long)]
+        day: u8,
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Submit an answer, solving the input for it unless one is given.
+    ///
+    /// Answers the site already turned down, or that fall outside a too
+    /// high/too low bound it gave, are refused without asking it.
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        site: SiteArgs,
+    },
+    /// Day 1 extras.
+    Day1 {
+        #[command(subcommand)]
+        command: Day1Command,
+    },
+    /// Day 3 extras.
+    Day3 {
+        #[command(subcommand)]
+        command: Day3Command,
+    },
+    /// Day 4 extras.
+    Day4 {
+        #[command(subcommand)]
+        command: Day4Command,
+    },
+    /// Day 5 extras.
+    Day5 {
+        #[command(subcommand)]
+        command: Day5Command,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day1Command {
+    /// Find the elves carrying the most in one pass over the input, without
+    /// holding it in memory, for inventories too big to read in whole.
+    Stream {
+        /// How many of the top elves to total, once per value. The defaults
+        /// give the two answers.
+        #[arg(long, default_values_t = [1, 3], value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+        k: Vec<usize>,
+        /// Integer type to total calories in, wider for bigger inventories.
+        #[arg(long, value_enum, default_value_t = Day1Width::U32)]
+        width: Day1Width,
+        #[command(flatten)]
+        dialect: Day1Dialect,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Summarise the elves' inventories: counts, spreads, a histogram of
+    /// totals and who carries the most.
+    Stats {
+        /// How many buckets to spread the totals over.
+        #[arg(long, default_value_t = 10)]
+        buckets: usize,
+        /// Print JSON instead of a table.
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        dialect: Day1Dialect,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+/// How forgiving to be about the layout of day 1's input.
+#[derive(Args)]
+struct Day1Dialect {
+    /// Only accept single blank lines between elves, LF line endings, no
+    /// trailing whitespace and nothing after the last line's newline, naming
+    /// the first line that deviates.
+    #[arg(long)]
+    strict: bool,
+}
+
+impl Day1Dialect {
+    fn elves<W: Width>(&self, input: Box<dyn BufRead>) -> Elves<Box<dyn BufRead>, W> {
+        match self.strict {
+            true => Elves::strict(input),
+            false => Elves::new(input),
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Day1Width {
+    U32,
+    U64,
+    U128,
+}
+
+#[derive(Subcommand)]
+enum Day3Command {
+    /// Total the badge priorities with elves in groups of a different size.
+    Badges {
+        #[command(flatten)]
+        group: GroupArgs,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Check every rucksack and group against the puzzle's rules, listing
+    /// each one broken with its line.
+    Validate {
+        #[command(flatten)]
+        group: GroupArgs,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show how an answer adds up: each rucksack's compartments and shared
+    /// type for part 1, each group's badge for part 2.
+    Explain {
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[command(flatten)]
+        group: GroupArgs,
+        /// Print CSV instead of a table.
+        #[arg(long)]
+        csv: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+/// How day 3's elves are grouped.
+#[derive(Args)]
+struct GroupArgs {
+    /// How many elves share a badge, the puzzle's being 3.
+    #[arg(long, default_value_t = day_3::GROUP_SIZE as u32, value_parser = clap::value_parser!(u32).range(2..))]
+    group_size: u32,
+}
+
+impl GroupArgs {
+    fn size(&self) -> usize {
+        self.group_size as usize
+    }
+}
+
+#[derive(Subcommand)]
+enum Day4Command {
+    /// Count the pairs by how their ranges meet: disjoint, touching, partial,
+    /// contains or identical, and which of those each answer counts.
+    Classify {
+        /// Print every pair's class too, by line.
+        #[arg(long)]
+        lines: bool,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day5Command {
+    /// Replay the crane moves in the terminal, one frame per move.
+    Animate {
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// How long each frame stays up, zero disables the animation.
+        #[arg(long, default_value_t = 200)]
+        frame_ms: u64,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let inputs = Inputs::new(cli.inputs, cli.year);
+
+    let result = match cli.command {
+        Command::Run { day, part, input } => solved(day)
+            .and_then(|()| input.read(&inputs, day))
+            .and_then(|input| run(day, part, &input)),
+        Command::Bench {
+            day,
+            iterations,
+            name,
+            output,
+            baseline,
+            threshold,
+        } => run_bench(
+            &inputs,
+            day,
+            iterations as usize,
+            &name,
+            output,
+            baseline,
+            threshold,
+        ),
+        Command::Verify { answers } => verify_answers(&inputs, answers),
+        Command::Parity {
+            day,
+            part,
+            name,
+            command,
+        } => check_parity(&inputs, day, part, name, &command),
+        Command::Fetch { day, site } => fetch(&site, &inputs, day),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            input,
+            site,
+        } => submit_answer(&site, &inputs, day, part, answer, &input),
+        Command::Day1 {
+            command:
+                Day1Command::Stream {
+                    k,
+                    width,
+                    dialect,
+                    input,
+                },
+        } => input.open(&inputs, 1).and_then(|input| match width {
+            Day1Width::U32 => stream_day_1(dialect.elves::<u32>(input), &k),
+            Day1Width::U64 => stream_day_1(dialect.elves::<u64>(input), &k),
+            Day1Width::U128 => stream_day_1(dialect.elves::<u128>(input), &k),
+        }),
+        Command::Day1 {
+            command:
+                Day1Command::Stats {
+                    buckets,
+                    json,
+                    dialect,
+                    input,
+                },
+        } => input
+            .open(&inputs, 1)
+            .and_then(|input| day_1_stats(dialect.elves(input), buckets, json)),
+        Command::Day3 {
+            command: Day3Command::Badges { group, input },
+        } => input
+            .read(&inputs, 3)
+            .and_then(|input| day_3_badges(&input, group.size())),
+        Command::Day3 {
+            command: Day3Command::Validate { group, input },
+        } => input
+            .read(&inputs, 3)
+            .and_then(|input| validate_day_3(&input, group.size())),
+        Command::Day3 {
+            command:
+                Day3Command::Explain {
+                    part,
+                    group,
+                    csv,
+                    input,
+                },
+        } => input.read(&inputs, 3).and_then(|input| {
+            let explained = match part {
+                1 => explain_part_1(&input).map(|rows| render(&rows, csv)),
+                _ => explain_part_2(&input, group.size()).map(|rows| render(&rows, csv)),
+            };
+            explained
+                .map(|out| print!("{out}"))
+                .map_err(|err| err.to_string())
+        }),
+        Command::Day4 {
+            command: Day4Command::Classify { lines, input },
+        } => input
+            .read(&inputs, 4)
+            .and_then(|input| classify_day_4(&input, lines)),
+        Command::Day5 {
+            command:
+                Day5Command::Animate {
+                    part,
+                    frame_ms,
+                    input,
+                },
+        } => input
+            .read(&inputs, 5)
+            .and_then(|input| animate(part, frame_ms, &input)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Whether `day` is solved, checked before reading its input so an unsolved
+/// day isn't reported as a missing file.
+fn solved(day: u8) -> Result<(), String> {
+    match DAYS.contains(&day) {
+        true => Ok(()),
+        false => Err(format!("day {day} isn't solved")),
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: &str) -> Result<(), String> {
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part).unwrap()],
+        None => Part::ALL.to_vec(),
+    };
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solve_day(day, part, input).ok_or(format!("day {day} isn't solved"))?;
+        let elapsed = start.elapsed();
+
+        let answer = answer.map_err(|err| err.to_string())?;
+        println!("Day {day} part {part}: {answer} ({elapsed:?})");
+    }
+
+    Ok(())
+}
+
+fn verify_answers(inputs: &Inputs, answers: Option<PathBuf>) -> Result<(), String> {
+    let path = answers.unwrap_or_else(|| inputs.answers_path());
+    let answers: KnownAnswers = std::fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|raw| raw.parse())
+        .map_err(|err| format!("can't load answers from {}: {err}", path.display()))?;
+
+    let checks = verify(inputs, &answers);
+    print!("{}", table(&checks));
+
+    let failed = checks
+        .iter()
+        .filter(|check| !check.outcome.passed())
+        .count();
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} of {} checks failed", checks.len())),
+    }
+}
+
+fn check_parity(
+    inputs: &Inputs,
+    day: u8,
+    part: u8,
+    names: Vec<String>,
+    command: &[String],
+) -> Result<(), String> {
+    let part = Part::try_from(part).unwrap();
+    let names = match names.is_empty() {
+        true => inputs
+            .names(day)
+            .map_err(|err| format!("can't list day {day}'s inputs: {err}"))?,
+        false => names,
+    };
+
+    let comparisons =
+        compare(inputs, day, part, &names, command).ok_or(format!("day {day} isn't solved"))?;
+    print!("{}", parity::table(&comparisons));
+
+    let differing = comparisons
+        .iter()
+        .filter(|comparison| !comparison.agrees())
+        .count();
+    match differing {
+        0 => Ok(()),
+        differing => Err(format!(
+            "{differing} of {} inputs gave different answers",
+            comparisons.len()
+        )),
+    }
+}
+
+fn run_bench(
+    inputs: &Inputs,
+    day: Option<u8>,
+    iterations: usize,
+    name: &str,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> Result<(), String> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => DAYS.to_vec(),
+    };
+
+    let mut report = Report {
+        iterations,
+        days: Vec::new(),
+    };
+    for day in days {
+        solved(day)?;
+        let input = inputs
+            .read(day, name)
+            .map_err(|err| format!("can't read input: {err}"))?;
+        let bench = bench_day(day, &input, iterations)
+            .ok_or(format!("day {day} isn't solved"))?
+            .map_err(|err| err.to_string())?;
+        report.days.push(bench);
+    }
+
+    print!("{}", bench::table(&report));
+
+    if let Some(output) = output {
+        let json = serde_json::to_string_pretty(&report).expect("reports serialise");
+        std::fs::write(&output, json + "\n")
+            .map_err(|err| format!("can't write {}: {err}", output.display()))?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let baseline: Report = std::fs::read_to_string(&baseline)
+        .map_err(|err| err.to_string())
+        .and_then(|raw| serde_json::from_str(&raw).map_err(|err| err.to_string()))
+        .map_err(|err| format!("can't load baseline {}: {err}", baseline.display()))?;
+
+    let regressions = regressions(&baseline, &report, threshold);
+    for regression in &regressions {
+        println!("regressed: {regression}");
+    }
+    match regressions.len() {
+        0 => Ok(()),
+        count => Err(format!("{count} steps regressed")),
+    }
+}
+
+fn fetch(site: &SiteArgs, inputs: &Inputs, day: u8) -> Result<(), String> {
+    let fetched = fetch_input(&site.client(), inputs, day)
+        .map_err(|err| format!("can't fetch day {day}: {err}"))?;
+
+    if fetched.downloaded {
+        println!("Downloaded {}", fetched.path.display());
+    } else {
+        println!("Already have {}", fetched.path.display());
+    }
+
+    Ok(())
+}
+
+fn submit_answer(
+    site: &SiteArgs,
+    inputs: &Inputs,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    input: &InputArgs,
+) -> Result<(), String> {
+    let part = Part::try_from(part).unwrap();
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            solved(day)?;
+            solve_day(day, part, &input.read(inputs, day)?)
+                .ok_or(format!("day {day} isn't solved"))?
+                .map_err(|err| err.to_string())?
+        }
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock is after 1970")
+        .as_secs();
+    let reply = submit(&site.client(), inputs, day, part, &answer, now)
+        .map_err(|err| format!("day {day} part {part} with {answer}: {err}"))?;
+
+    let wait = reply
+        .wait_secs
+        .map(|secs| format!(", wait {secs}s before trying again"))
+        .unwrap_or_default();
+    match reply.verdict {
+        Verdict::Correct => {
+            println!("Day {day} part {part}: {answer} is {}", reply.verdict);
+            Ok(())
+        }
+        verdict => Err(format!(
+            "day {day} part {part}: {answer} is {verdict}{wait}"
+        )),
+    }
+}
+
+fn stream_day_1<W: Width>(elves: Elves<Box<dyn BufRead>, W>, ks: &[usize]) -> Result<(), String> {
+    let start = Instant::now();
+    let most = ks.iter().copied().max().unwrap_or(0);
+    let top = top_k_streaming(elves, most).map_err(|err| match err {
+        Error::Solve { .. } => format!("{err}, try a wider --width"),
+        err => err.to_string(),
+    })?;
+    let elapsed = start.elapsed();
+
+    for &k in ks {
+        let Some(total) = sum_top_k(&top, k) else {
+            println!("Top {k}: only {} elves", top.len());
+            continue;
+        };
+        let total = total.map_err(|err| format!("{err}, try a wider --width"))?;
+        // Numbered from 1 like lines in the input.
+        let elves: Vec<String> = top[..k]
+            .iter()
+            .map(|elf| format!("#{}", elf.index + 1))
+            .collect();
+        let noun = if k == 1 { "elf" } else { "elves" };
+        println!("Top {k}: {total} carried by {noun} {}", elves.join(", "));
+    }
+    println!("Read in one pass ({elapsed:?})");
+
+    Ok(())
+}
+
+fn day_1_stats(
+    elves: Elves<Box<dyn BufRead>, u128>,
+    buckets: usize,
+    json: bool,
+) -> Result<(), String> {
+    let stats = day_1::stats::stats(elves, buckets).map_err(|err| err.to_string())?;
+
+    if json {
+        let json = serde_json::to_string_pretty(&stats).expect("stats serialise");
+        println!("{json}");
+    } else {
+        print!("{}", day_1::stats::table(&stats));
+    }
+
+    Ok(())
+}
+
+fn day_3_badges(input: &str, group_size: usize) -> Result<(), String> {
+    let rucksacks = Day3::parse(input).map_err(|err| err.to_string())?;
+    let total = badge_priorities(&rucksacks, group_size);
+
+    println!("Groups of {group_size}: {total}");
+    match rucksacks.len() % group_size {
+        0 => {}
+        left => println!("{left} rucksacks left over without a full group"),
+    }
+
+    Ok(())
+}
+
+fn validate_day_3(input: &str, group_size: usize) -> Result<(), String> {
+    let diagnostics = validate(input, group_size);
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    match diagnostics.len() {
+        0 => {
+            println!("All {} rucksacks are valid", input.lines().count());
+            Ok(())
+        }
+        count => Err(format!("{count} problems found")),
+    }
+}
+
+fn render<R: Row>(rows: &[R], csv: bool) -> String {
+    match csv {
+        true => explain::csv(rows),
+        false => explain::table(rows),
+    }
+}
+
+fn classify_day_4(input: &str, lines: bool) -> Result<(), String> {
+    let pairs = Day4::parse(input).map_err(|err| err.to_string())?;
+
+    if lines {
+        let rows: Vec<[String; 3]> = pairs
+            .iter()
+            .enumerate()
+            .map(|(idx, pair)| {
+                [
+                    (idx + 1).to_string(),
+                    pair.to_string(),
+                    pair.class().to_string(),
+                ]
+            })
+            .collect();
+        println!(
+            "{}",
+            align_columns(&[], &[Alignment::Right, Alignment::Left], &rows)
+        );
+    }
+    print!("{}", classify::table(&classify::counts(&pairs)));
+
+    Ok(())
+}
+
+fn animate(part: u8, frame_ms: u64, input: &str) -> Result<(), String> {
+    match part {
+        1 => solve_animated(
+            &day_5::Part::One(Stage::Actual),
+            enact_move_part_1,
+            input,
+            frame_ms,
+        ),
+        _ => solve_animated(
+            &day_5::Part::Two(Stage::Actual),
+            enact_move_part_2,
+            input,
+            frame_ms,
+        ),
+    }
+    .map_err(|err| err.to_string())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cli;
+    use clap::{error::ErrorKind, Parser};
+
+    #[test]
+    fn test_day_1_stream_needs_some_elves() {
+        let parse = |k: &str| Cli::try_parse_from(["aoc", "day1", "stream", "--k", k]).err();
+
+        assert_eq!(
+            parse("0").map(|err| err.kind()),
+            Some(ErrorKind::ValueValidation)
+        );
+        assert!(parse("2").is_none());
+    }
+}
@@ -0,0 +1,215 @@
+//! Keeping other ports of a solver honest.
+//!
+//! A reference implementation is any command that reads a puzzle input on
+//! stdin and prints its answer, like `php day-1/in.php`. Its answers are
+//! diffed against the Rust solver's on the same input files.
+
+use crate::{input::Inputs, solve_day, DAYS};
+use aoc_core::{table::align, Part};
+use std::{
+    io::{self, Write},
+    process::{Command, Stdio},
+    thread,
+};
+
+/// The Rust solver and the reference on one input. Either side failing is
+/// kept as its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub input: String,
+    pub rust: Result<String, String>,
+    pub reference: Result<String, String>,
+}
+
+impl Comparison {
+    /// Whether both sides gave the same answer.
+    pub fn agrees(&self) -> bool {
+        matches!((&self.rust, &self.reference), (Ok(rust), Ok(reference)) if rust == reference)
+    }
+}
+
+/// Runs `command` with `input` on its stdin, giving what it printed with
+/// surrounding whitespace trimmed, or why it failed.
+pub fn run_reference(command: &[String], input: &str) -> Result<String, String> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| "no reference command given".to_owned())?;
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| format!("can't run {program}: {err}"))?;
+
+    // Written from another thread while this one drains stdout and stderr, so
+    // a reference that prints as it reads can't fill a pipe and stall us both.
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let (written, output) = thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(input.as_bytes()));
+        let output = child.wait_with_output();
+        (writer.join().expect("writing stdin doesn't panic"), output)
+    });
+    // A reference that answers without reading all of its input is fine.
+    match written {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => return Err(err.to_string()),
+        _ => {}
+    }
+    let output = output.map_err(|err| err.to_string())?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(match stderr.trim() {
+            "" => format!("{program} exited with {}", output.status),
+            stderr => stderr.to_owned(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Compares `part` of `day` against `command` on each of the named inputs,
+/// or `None` when the day isn't solved here.
+pub fn compare(
+    inputs: &Inputs,
+    day: u8,
+    part: Part,
+    names: &[String],
+    command: &[String],
+) -> Option<Vec<Comparison>> {
+    if !DAYS.contains(&day) {
+        return None;
+    }
+
+    Some(
+        names
+            .iter()
+            .map(|name| {
+                let (rust, reference) = match inputs.read(day, name) {
+                    Err(err) => (Err(err.to_string()), Err("not run".to_owned())),
+                    Ok(input) => (
+                        solve_day(day, part, &input)
+                            .expect("day is solved")
+                            .map_err(|err| err.to_string()),
+                        run_reference(command, &input),
+                    ),
+                };
+
+                Comparison {
+                    input: name.clone(),
+                    rust,
+                    reference,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Lays the comparisons out as a table, one row each.
+pub fn table(comparisons: &[Comparison]) -> String {
+    let answer = |side: &Result<String, String>| match side {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("FAIL ({err})"),
+    };
+    let rows: Vec<[String; 4]> = comparisons
+        .iter()
+        .map(|comparison| {
+            let result = if comparison.agrees() {
+                "same"
+            } else {
+                "DIFFERS"
+            };
+            [
+                comparison.input.clone(),
+                answer(&comparison.rust),
+                answer(&comparison.reference),
+                result.to_owned(),
+            ]
+        })
+        .collect();
+
+    align(&["input", "rust", "reference", "result"], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, run_reference, table};
+    use crate::input::tests::inputs;
+    use aoc_core::Part;
+    use std::{path::Path, process::Command};
+
+    fn command(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reference_gets_input_on_stdin() {
+        assert_eq!(
+            run_reference(&command(&["sh", "-c", "wc -l"]), "a\nb\nc\n"),
+            Ok("3".to_owned())
+        );
+        assert_eq!(
+            run_reference(&command(&["sh", "-c", "echo oops >&2; exit 3"]), ""),
+            Err("oops".to_owned())
+        );
+        assert!(run_reference(&[], "").is_err());
+
+        // Far more than a pipe holds, echoed back while it's still being read.
+        let input = "1\n".repeat(1 << 20);
+        assert_eq!(
+            run_reference(&command(&["cat"]), &input),
+            Ok(input.trim().to_owned())
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let names = vec!["example".to_owned(), "missing".to_owned()];
+        let same = compare(
+            &inputs(),
+            1,
+            Part::One,
+            &names,
+            &command(&["sh", "-c", "echo 24000"]),
+        )
+        .unwrap();
+        assert!(same[0].agrees(), "{}", table(&same));
+        assert!(!same[1].agrees());
+
+        let differs = compare(
+            &inputs(),
+            1,
+            Part::One,
+            &names[..1],
+            &command(&["sh", "-c", "echo 23999"]),
+        )
+        .unwrap();
+        assert!(!differs[0].agrees());
+        assert!(table(&differs).contains("example  24000  23999      DIFFERS\n"));
+
+        assert_eq!(compare(&inputs(), 2, Part::One, &names, &[]), None);
+    }
+
+    #[test]
+    fn test_php_day_1_part_one() {
+        if Command::new("php").arg("--version").output().is_err() {
+            eprintln!("php isn't installed, skipping");
+            return;
+        }
+
+        let script = Path::new(env!("CARGO_MANIFEST_DIR")).join("../day-1/in.php");
+        let names = inputs().names(1).unwrap();
+        let comparisons = compare(
+            &inputs(),
+            1,
+            Part::One,
+            &names,
+            &command(&["php", script.to_str().unwrap()]),
+        )
+        .unwrap();
+        assert!(
+            comparisons.iter().all(|comparison| comparison.agrees()),
+            "{}",
+            table(&comparisons)
+        );
+    }
+}
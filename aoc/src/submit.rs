@@ -0,0 +1,457 @@
+//! Submitting answers, and remembering how each attempt went.
+//!
+//! Every attempt for a day is kept in `submissions.json` next to its inputs.
+//! That history is checked before anything is sent, so an answer the site has
+//! already turned down, or one past a too high/too low bound it gave us, never
+//! costs another wait.
+
+use crate::{
+    client::{Client, ClientError, Http},
+    input::Inputs,
+};
+use aoc_core::Part;
+use serde::{Deserialize, Serialize};
+use std::{
+    error::Error,
+    fmt::Display,
+    io,
+    path::{Path, PathBuf},
+};
+
+/// How long the site makes you wait after a wrong answer when it doesn't say.
+const DEFAULT_COOLDOWN_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    /// Wrong, without a hint which way.
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Sent before the last attempt's wait was over, so it wasn't checked.
+    TooSoon,
+    /// The part is already solved, or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::TooSoon => write!(f, "too soon"),
+            Verdict::WrongLevel => write!(f, "already solved or locked"),
+        }
+    }
+}
+
+/// What the site made of a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reply {
+    pub verdict: Verdict,
+    /// Seconds to wait before the next attempt.
+    pub wait_secs: Option<u64>,
+}
+
+impl Reply {
+    /// Reads the reply off the page the site answers a submission with.
+    pub fn parse(page: &str) -> Option<Reply> {
+        let verdict = if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::TooSoon
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            return None;
+        };
+
+        let wait_secs = parse_wait(page).or(if verdict.is_wrong() {
+            Some(DEFAULT_COOLDOWN_SECS)
+        } else {
+            None
+        });
+
+        Some(Reply { verdict, wait_secs })
+    }
+}
+
+/// Picks the wait out of "You have 1m 40s left to wait" or "please wait 5
+/// minutes before trying again".
+fn parse_wait(page: &str) -> Option<u64> {
+    if let Some((before, _)) = page.split_once(" left to wait") {
+        let (_, left) = before.rsplit_once("You have ")?;
+        return left.split_whitespace().try_fold(0, |secs, part| {
+            let unit = part.chars().last()?;
+            let amount: u64 = part[..part.len() - unit.len_utf8()].parse().ok()?;
+            match unit {
+                'h' => Some(secs + amount * 3600),
+                'm' => Some(secs + amount * 60),
+                's' => Some(secs + amount),
+                _ => None,
+            }
+        });
+    }
+
+    let page = page.to_lowercase();
+    let (_, after) = page.split_once("please wait ")?;
+    let mut words = after.split_whitespace();
+    let amount = match words.next()? {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    match words.next()? {
+        "minute" | "minutes" => Some(amount * 60),
+        "second" | "seconds" => Some(amount),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time the attempt was made.
+    pub at: u64,
+    /// Unix time before which the site won't take another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+}
+
+/// Why an answer wasn't sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    Solved {
+        answer: String,
+    },
+    KnownWrong {
+        verdict: Verdict,
+    },
+    /// At or above an answer that was too high.
+    AboveBound {
+        too_high: String,
+    },
+    /// At or below an answer that was too low.
+    BelowBound {
+        too_low: String,
+    },
+    RateLimited {
+        wait_secs: u64,
+    },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong { verdict } => {
+                write!(f, "already submitted, it was {verdict}")
+            }
+            Refusal::AboveBound { too_high } => {
+                write!(f, "{too_high} was already too high")
+            }
+            Refusal::BelowBound { too_low } => write!(f, "{too_low} was already too low"),
+            Refusal::RateLimited { wait_secs } => {
+                write!(f, "the site wants another {wait_secs}s before the next try")
+            }
+        }
+    }
+}
+
+/// Every attempt made for one day.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn path(inputs: &Inputs, day: u8) -> PathBuf {
+        inputs.day_dir(day).join("submissions.json")
+    }
+
+    /// Loads the history at `path`, which is empty if nothing was submitted.
+    pub fn load(path: &Path) -> io::Result<History> {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => serde_json::from_str(&raw).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let raw = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        std::fs::write(path, raw + "\n")
+    }
+
+    /// Decides whether sending `answer` for `part` at `now` could possibly help.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<(), Refusal> {
+        let part = u8::from(part);
+        let attempts = self.attempts.iter().filter(|attempt| attempt.part == part);
+
+        let mut too_high: Option<(i128, &str)> = None;
+        let mut too_low: Option<(i128, &str)> = None;
+        for attempt in attempts.clone() {
+            if attempt.verdict == Verdict::Correct {
+                return Err(Refusal::Solved {
+                    answer: attempt.answer.clone(),
+                });
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Err(Refusal::KnownWrong {
+                    verdict: attempt.verdict,
+                });
+            }
+
+            let Ok(value) = attempt.answer.parse::<i128>() else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh if too_high.is_none_or(|(bound, _)| value < bound) => {
+                    too_high = Some((value, &attempt.answer));
+                }
+                Verdict::TooLow if too_low.is_none_or(|(bound, _)| value > bound) => {
+                    too_low = Some((value, &attempt.answer));
+                }
+                _ => {}
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some((_, too_high)) = too_high.filter(|(bound, _)| value >= *bound) {
+                return Err(Refusal::AboveBound {
+                    too_high: too_high.to_owned(),
+                });
+            }
+            if let Some((_, too_low)) = too_low.filter(|(bound, _)| value <= *bound) {
+                return Err(Refusal::BelowBound {
+                    too_low: too_low.to_owned(),
+                });
+            }
+        }
+
+        let wait_until = attempts.filter_map(|attempt| attempt.wait_until).max();
+        match wait_until {
+            Some(wait_until) if wait_until > now => Err(Refusal::RateLimited {
+                wait_secs: wait_until - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, reply: Reply, now: u64) {
+        self.attempts.push(Attempt {
+            part: u8::from(part),
+            answer: answer.to_owned(),
+            verdict: reply.verdict,
+            at: now,
+            wait_until: reply.wait_secs.map(|wait| now + wait),
+        });
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting, {refusal}"),
+            SubmitError::Client(err) => write!(f, "{err}"),
+            SubmitError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(value: ClientError) -> Self {
+        SubmitError::Client(value)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(value: io::Error) -> Self {
+        SubmitError::Io(value)
+    }
+}
+
+/// Submits `answer` unless the day's history says it can't be right, then
+/// records whatever the site said.
+pub fn submit<H: Http>(
+    client: &Client<H>,
+    inputs: &Inputs,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Reply, SubmitError> {
+    let path = History::path(inputs, day);
+    let mut history = History::load(&path)?;
+    history
+        .check(part, answer, now)
+        .map_err(SubmitError::Refused)?;
+
+    let reply = client.submit(inputs.year(), day, part, answer)?;
+    history.record(part, answer, reply, now);
+    history.save(&path)?;
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{submit, History, Refusal, Reply, SubmitError, Verdict};
+    use crate::{
+        client::{tests::FakeSite, Client},
+        input::Inputs,
+    };
+    use aoc_core::Part;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data; there are also some \
+        general tips on the <a href=\"/2022/about\">about page</a>. Please wait one minute \
+        before trying again. [<a href=\"/2022/day/4\">Return to Day 4</a>]</p></article>";
+
+    fn reply(verdict: Verdict, wait_secs: Option<u64>) -> Reply {
+        Reply { verdict, wait_secs }
+    }
+
+    #[test]
+    fn test_parse_replies() {
+        assert_eq!(
+            Reply::parse("<p>That's the right answer! You are one gold star closer.</p>"),
+            Some(reply(Verdict::Correct, None))
+        );
+        assert_eq!(
+            Reply::parse(TOO_HIGH),
+            Some(reply(Verdict::TooHigh, Some(60)))
+        );
+        assert_eq!(
+            Reply::parse("That's not the right answer; your answer is too low. please wait 5 minutes before trying again."),
+            Some(reply(Verdict::TooLow, Some(300)))
+        );
+        assert_eq!(
+            Reply::parse("That's not the right answer. If you're stuck, make sure..."),
+            Some(reply(Verdict::Incorrect, Some(60)))
+        );
+        assert_eq!(
+            Reply::parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 40s left to wait."),
+            Some(reply(Verdict::TooSoon, Some(100)))
+        );
+        assert_eq!(
+            Reply::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Some(reply(Verdict::WrongLevel, None))
+        );
+        assert_eq!(Reply::parse("<html>Teapot</html>"), None);
+    }
+
+    #[test]
+    fn test_refuses_known_wrong_and_bounds() {
+        let mut history = History::default();
+        history.record(Part::One, "500", reply(Verdict::TooHigh, Some(60)), 0);
+        history.record(Part::One, "100", reply(Verdict::TooLow, Some(60)), 100);
+        history.record(Part::One, "300", reply(Verdict::Incorrect, Some(60)), 200);
+
+        assert_eq!(
+            history.check(Part::One, "300", 1000),
+            Err(Refusal::KnownWrong {
+                verdict: Verdict::Incorrect
+            })
+        );
+        assert_eq!(
+            history.check(Part::One, "700", 1000),
+            Err(Refusal::AboveBound {
+                too_high: "500".to_owned()
+            })
+        );
+        assert_eq!(
+            history.check(Part::One, "100", 1000),
+            Err(Refusal::KnownWrong {
+                verdict: Verdict::TooLow
+            })
+        );
+        assert_eq!(
+            history.check(Part::One, "42", 1000),
+            Err(Refusal::BelowBound {
+                too_low: "100".to_owned()
+            })
+        );
+        assert_eq!(history.check(Part::One, "250", 1000), Ok(()));
+        assert_eq!(history.check(Part::Two, "700", 1000), Ok(()));
+    }
+
+    #[test]
+    fn test_refuses_while_waiting_and_once_solved() {
+        let mut history = History::default();
+        history.record(Part::Two, "CMZ", reply(Verdict::Incorrect, Some(60)), 1000);
+
+        assert_eq!(
+            history.check(Part::Two, "MCD", 1030),
+            Err(Refusal::RateLimited { wait_secs: 30 })
+        );
+        assert_eq!(history.check(Part::Two, "MCD", 1060), Ok(()));
+
+        history.record(Part::Two, "MCD", reply(Verdict::Correct, None), 1060);
+        assert_eq!(
+            history.check(Part::Two, "XYZ", 2000),
+            Err(Refusal::Solved {
+                answer: "MCD".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn test_submit_records_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs = Inputs::new(dir.path(), 2022);
+        let site = FakeSite::new(200, TOO_HIGH);
+        let client = Client::new(&site, "http://site", "cookie");
+
+        let reply = submit(&client, &inputs, 4, Part::One, "900", 5000).unwrap();
+        assert_eq!(reply.verdict, Verdict::TooHigh);
+        assert_eq!(
+            *site.requests.borrow(),
+            vec!["POST http://site/2022/day/4/answer level=1&answer=900"]
+        );
+
+        let history = History::load(&History::path(&inputs, 4)).unwrap();
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].wait_until, Some(5060));
+
+        let refused = submit(&client, &inputs, 4, Part::One, "950", 9000).unwrap_err();
+        assert!(matches!(
+            refused,
+            SubmitError::Refused(Refusal::AboveBound { .. })
+        ));
+        assert_eq!(site.requests.borrow().len(), 1);
+    }
+}
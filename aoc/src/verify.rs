@@ -0,0 +1,159 @@
+//! Checking every solver against the known answer registry.
+
+use crate::{input::Inputs, solve_day, DAYS};
+use aoc_core::{answers::KnownAnswers, table::align, Error, Part};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        actual: String,
+    },
+    /// The solver ran but found no answer.
+    NoAnswer,
+    /// The input named in the registry couldn't be read.
+    MissingInput(String),
+    /// The solver rejected the input.
+    BadInput(String),
+    Unsolved,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        *self == Outcome::Pass
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { .. } => write!(f, "FAIL"),
+            Outcome::NoAnswer => write!(f, "FAIL (no answer)"),
+            Outcome::MissingInput(err) | Outcome::BadInput(err) => write!(f, "FAIL ({err})"),
+            Outcome::Unsolved => write!(f, "FAIL (day isn't solved)"),
+        }
+    }
+}
+
+/// How one solver did against one known answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub expected: String,
+    pub outcome: Outcome,
+}
+
+/// Runs the solver for every answer in `answers` on its input from `inputs`.
+pub fn verify(inputs: &Inputs, answers: &KnownAnswers) -> Vec<Check> {
+    answers
+        .iter()
+        .map(|known| {
+            let expected = known.answer.to_string();
+            // Checked first, so an unsolved day isn't blamed on its input.
+            let outcome = if !DAYS.contains(&known.day) {
+                Outcome::Unsolved
+            } else {
+                match inputs.read(known.day, known.input) {
+                    Err(err) => Outcome::MissingInput(err.to_string()),
+                    Ok(input) => match solve_day(known.day, known.part, &input) {
+                        None => Outcome::Unsolved,
+                        Some(Err(Error::NoAnswer { .. })) => Outcome::NoAnswer,
+                        Some(Err(err)) => Outcome::BadInput(err.to_string()),
+                        Some(Ok(actual)) if actual == expected => Outcome::Pass,
+                        Some(Ok(actual)) => Outcome::Fail { actual },
+                    },
+                }
+            };
+
+            Check {
+                day: known.day,
+                input: known.input.to_owned(),
+                part: known.part,
+                expected,
+                outcome,
+            }
+        })
+        .collect()
+}
+
+/// Lays the checks out as a table, one row each.
+pub fn table(checks: &[Check]) -> String {
+    let rows: Vec<[String; 6]> = checks
+        .iter()
+        .map(|check| {
+            let actual = match &check.outcome {
+                Outcome::Pass => check.expected.clone(),
+                Outcome::Fail { actual } => actual.clone(),
+                _ => "-".to_owned(),
+            };
+            [
+                check.day.to_string(),
+                check.input.clone(),
+                check.part.to_string(),
+                check.expected.clone(),
+                actual,
+                check.outcome.to_string(),
+            ]
+        })
+        .collect();
+
+    align(
+        &["day", "input", "part", "expected", "actual", "result"],
+        &rows,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{table, verify, Outcome};
+    use crate::input::tests::inputs;
+    use aoc_core::answers::KnownAnswers;
+
+    #[test]
+    fn test_checked_in_answers_pass() {
+        let inputs = inputs();
+        let answers: KnownAnswers = std::fs::read_to_string(inputs.answers_path())
+            .unwrap()
+            .parse()
+            .unwrap();
+        let checks = verify(&inputs, &answers);
+        assert!(!checks.is_empty());
+
+        let failed: Vec<_> = checks
+            .iter()
+            .filter(|check| !check.outcome.passed())
+            .collect();
+        assert!(failed.is_empty(), "{}", table(&checks));
+    }
+
+    #[test]
+    fn test_failures() {
+        let answers: KnownAnswers = "[day-4.example]\npart-1 = 3\n\
+            [day-4.missing]\npart-1 = 1\n\
+            [day-2.actual]\npart-2 = 1\n"
+            .parse()
+            .unwrap();
+
+        let checks = verify(&inputs(), &answers);
+        assert_eq!(checks[0].outcome, Outcome::Unsolved);
+        assert_eq!(
+            checks[1].outcome,
+            Outcome::Fail {
+                actual: "2".to_owned()
+            }
+        );
+        assert!(matches!(checks[2].outcome, Outcome::MissingInput(_)));
+
+        let table = table(&checks[..2]);
+        assert_eq!(
+            table,
+            "day  input    part  expected  actual  result\n\
+             2    actual   2     1         -       FAIL (day isn't solved)\n\
+             4    example  1     3         2       FAIL\n"
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use aoc_core::{Error, Part, Solution};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{Debug, Display},
+    io::BufRead,
+    marker::PhantomData,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+pub mod stats;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Parsed<'a> = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<u32>, Error> {
+        Elves::new(input.as_bytes())
+            .map(|elf| elf.map(|elf| elf.calories))
+            .collect()
+    }
+
+    fn part_one(elves: &Vec<u32>) -> Result<u32, Error> {
+        sum_top_k(&top_k(elves.iter().copied(), 1), 1).unwrap_or(Err(Error::NoAnswer {
+            day: 1,
+            part: Part::One,
+        }))
+    }
+
+    fn part_two(elves: &Vec<u32>) -> Result<u32, Error> {
+        sum_top_k(&top_k(elves.iter().copied(), 3), 3).unwrap_or(Err(Error::NoAnswer {
+            day: 1,
+            part: Part::Two,
+        }))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Result<u32, Error> {
+    Day1::part_one(&Day1::parse(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<u32, Error> {
+    Day1::part_two(&Day1::parse(input)?)
+}
+
+/// An unsigned integer to total calories in, wide enough for the input.
+///
+/// The release profile turns overflow checks off, so totals only ever go up by
+/// [`Width::checked_add`].
+pub trait Width:
+    Copy + Ord + Default + Debug + Display + FromStr<Err = ParseIntError> + Into<u128>
+{
+    const NAME: &'static str;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! width {
+    ($($int:ty),*) => {
+        $(
+            impl Width for $int {
+                const NAME: &'static str = stringify!($int);
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+width!(u32, u64, u128);
+
+/// What one elf is carrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inventory<W = u32> {
+    pub items: usize,
+    pub calories: W,
+}
+
+/// Each elf's inventory, read a line at a time from `reader` so only the line
+/// being summed is ever held in memory.
+///
+/// Elves are separated by blank lines. By default lines may end in CRLF or
+/// trailing whitespace, and any number of blank lines may come between,
+/// before or after the elves. [`Elves::strict`] allows none of that: one
+/// blank line between elves, LF endings and at most the one newline ending
+/// the last line.
+///
+/// Anything that isn't a calorie count is an error, as is a total too big
+/// for `W`, after which the iterator stops.
+pub struct Elves<R, W = u32> {
+    reader: R,
+    strict: bool,
+    buf: String,
+    line: usize,
+    /// How many characters the line before `line` had, for pointing past it.
+    last_len: usize,
+    elves: usize,
+    done: bool,
+    width: PhantomData<W>,
+}
+
+impl<R: BufRead, W: Width> Elves<R, W> {
+    pub fn new(reader: R) -> Self {
+        Elves {
+            reader,
+            strict: false,
+            buf: String::new(),
+            line: 0,
+            last_len: 0,
+            elves: 0,
+            done: false,
+            width: PhantomData,
+        }
+    }
+
+    /// Elves laid out exactly as the puzzle gives them, with or without the
+    /// newline a downloaded input ends in, failing on the first line that
+    /// deviates.
+    pub fn strict(reader: R) -> Self {
+        Elves {
+            strict: true,
+            ..Elves::new(reader)
+        }
+    }
+
+    fn fail(&mut self, error: Error) -> Option<Result<Inventory<W>, Error>> {
+        self.done = true;
+        Some(Err(error))
+    }
+}
+
+impl<R: BufRead, W: Width> Iterator for Elves<R, W> {
+    type Item = Result<Inventory<W>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.elves += 1;
+        let mut inventory = Inventory {
+            items: 0,
+            calories: W::default(),
+        };
+        loop {
+            self.last_len = self.buf.trim_end_matches('\n').chars().count();
+            self.buf.clear();
+            self.line += 1;
+            let read = match self.reader.read_line(&mut self.buf) {
+                Ok(read) => read,
+                Err(err) => return self.fail(Error::parse(1, self.line, 1, err.to_string())),
+            };
+
+            let entry = if self.strict {
+                // Whatever follows the last newline is an entry too, even if
+                // there's nothing there.
+                let (entry, terminated) = match self.buf.strip_suffix('\n') {
+                    Some(entry) => (entry, true),
+                    None => (self.buf.as_str(), false),
+                };
+                if let Some((line, column, message)) =
+                    self.deviation(entry, terminated, inventory.items)
+                {
+                    return self.fail(Error::parse(1, line, column, message));
+                }
+                match (entry.is_empty(), terminated) {
+                    (true, true) => return Some(Ok(inventory)),
+                    (true, false) => {
+                        self.done = true;
+                        return (inventory.items > 0).then_some(Ok(inventory));
+                    }
+                    (false, _) => {}
+                }
+                entry
+            } else {
+                if read == 0 {
+                    self.done = true;
+                    return (inventory.items > 0).then_some(Ok(inventory));
+                }
+                match self.buf.trim_end() {
+                    "" if inventory.items > 0 => return Some(Ok(inventory)),
+                    "" => continue,
+                    entry => entry,
+                }
+            };
+            let terminated = self.buf.ends_with('\n');
+
+            let calories = match entry.parse::<W>() {
+                Ok(calories) => calories,
+                Err(err) => {
+                    let message = format!("`{entry}` isn't a calorie count: {err}");
+                    return self.fail(Error::parse(1, self.line, 1, message));
+                }
+            };
+            let Some(total) = inventory.calories.checked_add(calories) else {
+                return self.fail(Error::Solve {
+                    day: 1,
+                    line: Some(self.line),
+                    message: format!("elf #{}'s total doesn't fit in {}", self.elves, W::NAME),
+                });
+            };
+            inventory.calories = total;
+            inventory.items += 1;
+
+            if !terminated {
+                self.done = true;
+                return Some(Ok(inventory));
+            }
+        }
+    }
+}
+
+impl<R, W> Elves<R, W> {
+    /// Where and how `entry`, the current line, breaks the strict layout.
+    fn deviation(
+        &self,
+        entry: &str,
+        terminated: bool,
+        items: usize,
+    ) -> Option<(usize, usize, &'static str)> {
+        let content = entry.trim_end();
+        let column = content.chars().count() + 1;
+
+        if entry.ends_with('\r') {
+            Some((self.line, column, "line ends in CRLF rather than LF"))
+        } else if content != entry {
+            Some((self.line, column, "trailing whitespace"))
+        } else if !entry.is_empty() {
+            None
+        } else if !terminated && self.line == 1 {
+            Some((1, 1, "there are no elves"))
+        } else if !terminated && self.last_len == 0 {
+            Some((self.line - 1, 1, "blank line after the last elf"))
+        } else if !terminated || items > 0 {
+            None
+        } else if self.elves == 1 {
+            Some((self.line, 1, "blank line before the first elf"))
+        } else {
+            Some((self.line, 1, "more than one blank line between elves"))
+        }
+    }
+}
+
+/// One of the elves carrying the most, by where it comes in the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf<W = u32> {
+    /// How many elves come before it.
+    pub index: usize,
+    pub calories: W,
+}
+
+/// The `k` biggest totals seen so far, kept in a min-heap so the smallest of
+/// them is the one to go when a bigger elf turns up.
+struct Podium<W> {
+    k: usize,
+    // Ties go to the earlier elf, so a later index sorts lower.
+    heap: BinaryHeap<Reverse<(W, Reverse<usize>)>>,
+    seen: usize,
+}
+
+impl<W: Width> Podium<W> {
+    fn new(k: usize) -> Self {
+        Podium {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+            seen: 0,
+        }
+    }
+
+    fn push(&mut self, calories: W) {
+        let elf = Reverse((calories, Reverse(self.seen)));
+        self.seen += 1;
+
+        if self.heap.len() < self.k {
+            self.heap.push(elf);
+        } else if self.heap.peek().is_some_and(|smallest| elf < *smallest) {
+            self.heap.pop();
+            self.heap.push(elf);
+        }
+    }
+
+    fn into_elves(self) -> Vec<Elf<W>> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect()
+    }
+}
+
+/// The `k` elves carrying the most calories, biggest first, in O(n log k).
+///
+/// Elves with equal totals come in input order. There are fewer than `k` when
+/// there aren't that many elves.
+pub fn top_k<W: Width>(totals: impl IntoIterator<Item = W>, k: usize) -> Vec<Elf<W>> {
+    let mut podium = Podium::new(k);
+    for calories in totals {
+        podium.push(calories);
+    }
+
+    podium.into_elves()
+}
+
+/// [`top_k`] over `elves`, in a single pass that only ever keeps `k` of them
+/// around.
+pub fn top_k_streaming<W: Width, R: BufRead>(
+    elves: Elves<R, W>,
+    k: usize,
+) -> Result<Vec<Elf<W>>, Error> {
+    let mut podium = Podium::new(k);
+    for elf in elves {
+        podium.push(elf?.calories);
+    }
+
+    Ok(podium.into_elves())
+}
+
+/// The sum of the `k` biggest totals, or `None` when there are fewer elves.
+pub fn sum_top_k<W: Width>(elves: &[Elf<W>], k: usize) -> Option<Result<W, Error>> {
+    let total = elves
+        .get(..k)?
+        .iter()
+        .try_fold(W::default(), |total, elf| total.checked_add(elf.calories));
+
+    Some(total.ok_or_else(|| Error::Solve {
+        day: 1,
+        line: None,
+        message: format!("the top {k} elves' total doesn't fit in {}", W::NAME),
+    }))
+}
+
+#[cfg(test)]
+mod fixtures {
+    pub const EXAMPLE_INPUT: &str = include_str!("../../inputs/2022/day-1/example.txt");
+    pub const ACTUAL_INPUT: &str = include_str!("../../inputs/2022/day-1/actual.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+        solve_part_1, solve_part_2, sum_top_k, top_k, top_k_streaming, Elf, Elves,
+    };
+    use aoc_core::{answers::expected, Error, Part};
+
+    #[test]
+    fn test_elves() {
+        let elves: Vec<(usize, u32)> = Elves::new(EXAMPLE_INPUT.as_bytes())
+            .map(|elf| elf.map(|elf| (elf.items, elf.calories)))
+            .collect::<Result<_, Error>>()
+            .unwrap();
+        assert_eq!(
+            elves,
+            vec![(3, 6000), (1, 4000), (2, 11000), (3, 24000), (1, 10000)]
+        );
+
+        let line = |input: &str| match Elves::<_, u32>::new(input.as_bytes()).find_map(Result::err)
+        {
+            Some(Error::Parse { line, .. }) => line,
+            other => panic!("expected a parse error, got {other:?}"),
+        };
+        assert_eq!(line("1\n2\n\n3\nfour"), 5);
+        assert_eq!(line("1\n2\n\n 3"), 4);
+    }
+
+    #[test]
+    fn test_dialects() {
+        let totals = |input: &str| {
+            Elves::<_, u32>::new(input.as_bytes())
+                .map(|elf| elf.map(|elf| elf.calories))
+                .collect::<Result<Vec<_>, Error>>()
+                .unwrap()
+        };
+        let crlf = EXAMPLE_INPUT.replace('\n', "\r\n");
+        let sloppy = format!(
+            "\n\n{}  \n\n\n \t\n",
+            EXAMPLE_INPUT.replace("\n\n", "\n\n\n")
+        );
+        for input in [EXAMPLE_INPUT, &crlf, &sloppy, &format!("{EXAMPLE_INPUT}\n")] {
+            assert_eq!(totals(input), vec![6000, 4000, 11000, 24000, 10000]);
+            assert_eq!(solve_part_2(input), Ok(expected(1, "example", Part::Two)));
+        }
+        assert!(totals("").is_empty());
+        assert!(totals("\n\n").is_empty());
+
+        let deviation =
+            |input: &str| match Elves::<_, u32>::strict(input.as_bytes()).find_map(Result::err) {
+                Some(Error::Parse {
+                    line,
+                    column,
+                    message,
+                    ..
+                }) => (line, column, message),
+                other => panic!("expected a parse error, got {other:?}"),
+            };
+        let deviation = |input| {
+            let (line, column, message) = deviation(input);
+            format!("{line}:{column} {message}")
+        };
+        assert_eq!(deviation(&crlf), "1:5 line ends in CRLF rather than LF");
+        assert_eq!(deviation("1\n2 \n\n3"), "2:2 trailing whitespace");
+        assert_eq!(
+            deviation("1\n\n\n2"),
+            "3:1 more than one blank line between elves"
+        );
+        assert_eq!(deviation("\n1"), "1:1 blank line before the first elf");
+        assert_eq!(deviation("1\n22\n\n"), "3:1 blank line after the last elf");
+        assert_eq!(
+            deviation("1\n\n22\n\n"),
+            "4:1 blank line after the last elf"
+        );
+        assert_eq!(deviation(""), "1:1 there are no elves");
+        assert_eq!(
+            deviation("1\n2\n\nthree"),
+            "4:1 `three` isn't a calorie count: invalid digit found in string"
+        );
+        for input in [EXAMPLE_INPUT, &format!("{EXAMPLE_INPUT}\n")] {
+            let totals: Vec<u32> = Elves::<_, u32>::strict(input.as_bytes())
+                .map(|elf| elf.unwrap().calories)
+                .collect();
+            assert_eq!(totals, vec![6000, 4000, 11000, 24000, 10000]);
+        }
+        assert!(Elves::<_, u32>::strict(ACTUAL_INPUT.as_bytes()).all(|elf| elf.is_ok()));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            solve_part_1(EXAMPLE_INPUT),
+            Ok(expected(1, "example", Part::One))
+        );
+        assert_eq!(
+            solve_part_1(ACTUAL_INPUT),
+            Ok(expected(1, "actual", Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            solve_part_2(EXAMPLE_INPUT),
+            Ok(expected(1, "example", Part::Two))
+        );
+        assert_eq!(
+            solve_part_2(ACTUAL_INPUT),
+            Ok(expected(1, "actual", Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_top_k() {
+        let elf = |index, calories| Elf { index, calories };
+        let totals: [u32; 6] = [6000, 4000, 11000, 24000, 10000, 11000];
+
+        assert_eq!(top_k(totals, 1), vec![elf(3, 24000)]);
+        assert_eq!(
+            top_k(totals, 3),
+            vec![elf(3, 24000), elf(2, 11000), elf(5, 11000)]
+        );
+        assert_eq!(top_k(totals, 10).len(), 6);
+        assert!(top_k(totals, 0).is_empty());
+
+        assert_eq!(sum_top_k(&top_k(totals, 3), 2), Some(Ok(35000)));
+        assert_eq!(sum_top_k(&top_k([5_u32, 7], 3), 3), None);
+    }
+
+    #[test]
+    fn test_streaming() {
+        let top = top_k_streaming(Elves::<_, u32>::new(ACTUAL_INPUT.as_bytes()), 3).unwrap();
+        assert_eq!(
+            sum_top_k(&top, 1),
+            Some(Ok(expected(1, "actual", Part::One)))
+        );
+        assert_eq!(
+            sum_top_k(&top, 3),
+            Some(Ok(expected(1, "actual", Part::Two)))
+        );
+        assert!(top_k_streaming(Elves::<_, u32>::new("1\nx".as_bytes()), 3).is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = format!("1\n\n{}\n\n{}\n1", u32::MAX, u32::MAX);
+        assert_eq!(
+            top_k_streaming(Elves::<_, u32>::new(input.as_bytes()), 1),
+            Err(Error::Solve {
+                day: 1,
+                line: Some(6),
+                message: "elf #3's total doesn't fit in u32".to_owned()
+            })
+        );
+
+        let top = top_k_streaming(Elves::<_, u64>::new(input.as_bytes()), 2).unwrap();
+        assert_eq!(top[0].calories, u64::from(u32::MAX) + 1);
+        assert_eq!(sum_top_k(&top, 2), Some(Ok(2 * u64::from(u32::MAX) + 1)));
+
+        let top = top_k(
+            input
+                .split("\n\n")
+                .map(|elf| elf.parse::<u32>().unwrap_or(u32::MAX)),
+            2,
+        );
+        assert!(matches!(
+            sum_top_k(&top, 2),
+            Some(Err(Error::Solve { line: None, .. }))
+        ));
+        assert!(solve_part_2(&input).is_err());
+    }
+}
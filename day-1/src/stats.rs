@@ -0,0 +1,255 @@
+//! A summary of everybody's inventory, beyond the two answers.
+
+use crate::{Elves, Inventory, Width};
+use aoc_core::{
+    table::{align, align_columns, Alignment},
+    Error,
+};
+use serde::Serialize;
+use std::io::BufRead;
+
+/// The smallest, biggest and mean of something counted per elf.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Spread {
+    pub min: u128,
+    pub max: u128,
+    pub mean: f64,
+}
+
+impl Spread {
+    fn of(values: &[u128]) -> Self {
+        Spread {
+            min: values.iter().copied().min().unwrap_or(0),
+            max: values.iter().copied().max().unwrap_or(0),
+            mean: values.iter().sum::<u128>() as f64 / values.len().max(1) as f64,
+        }
+    }
+}
+
+/// How many elves have a total between `from` and `to`, both included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u128,
+    pub to: u128,
+    pub elves: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub elves: usize,
+    pub items: Spread,
+    pub calories: Spread,
+    pub median_calories: f64,
+    pub histogram: Vec<Bucket>,
+    /// Every elf carrying the most, by how many elves come before it. More
+    /// than one means a tie.
+    pub top: Vec<usize>,
+}
+
+impl Stats {
+    /// Summarises `elves`, spreading their totals over at most `buckets`
+    /// equally wide buckets.
+    pub fn new<W: Width>(elves: &[Inventory<W>], buckets: usize) -> Self {
+        let items: Vec<u128> = elves.iter().map(|elf| elf.items as u128).collect();
+        let mut calories: Vec<u128> = elves.iter().map(|elf| elf.calories.into()).collect();
+        let spread = Spread::of(&calories);
+
+        let top = elves
+            .iter()
+            .enumerate()
+            .filter(|(_, elf)| elf.calories.into() == spread.max)
+            .map(|(index, _)| index)
+            .collect();
+
+        calories.sort_unstable();
+        let middle = calories.len() / 2;
+        let median_calories = match calories.len() {
+            0 => 0.0,
+            len if len.is_multiple_of(2) => (calories[middle - 1] + calories[middle]) as f64 / 2.0,
+            _ => calories[middle] as f64,
+        };
+
+        Stats {
+            elves: elves.len(),
+            items: Spread::of(&items),
+            histogram: histogram(&calories, &spread, buckets),
+            calories: spread,
+            median_calories,
+            top,
+        }
+    }
+}
+
+fn histogram(sorted: &[u128], spread: &Spread, buckets: usize) -> Vec<Bucket> {
+    if sorted.is_empty() || buckets == 0 {
+        return Vec::new();
+    }
+
+    let width = (spread.max - spread.min)
+        .saturating_add(1)
+        .div_ceil(buckets as u128);
+    let mut histogram: Vec<Bucket> = (0..buckets as u128)
+        .map(|bucket| spread.min + bucket * width)
+        .take_while(|&from| from <= spread.max)
+        .map(|from| Bucket {
+            from,
+            to: (from.saturating_add(width) - 1).min(spread.max),
+            elves: 0,
+        })
+        .collect();
+    for &calories in sorted {
+        histogram[((calories - spread.min) / width) as usize].elves += 1;
+    }
+
+    histogram
+}
+
+/// [`Stats`] for every one of `elves`, only holding on to each elf's
+/// inventory rather than the whole input. Totals are kept as `u128`, so
+/// only absurd inputs overflow.
+pub fn stats<R: BufRead>(elves: Elves<R, u128>, buckets: usize) -> Result<Stats, Error> {
+    let elves: Vec<Inventory<u128>> = elves.collect::<Result<_, _>>()?;
+    Ok(Stats::new(&elves, buckets))
+}
+
+/// Lays the stats out for reading, elves numbered from 1.
+pub fn table(stats: &Stats) -> String {
+    let top: Vec<String> = stats
+        .top
+        .iter()
+        .map(|index| format!("#{}", index + 1))
+        .collect();
+    let most = match top.len() {
+        0 => "-".to_owned(),
+        1 => top.join(", "),
+        _ => format!("{} (tied)", top.join(", ")),
+    };
+    let summary = align(
+        &[],
+        &[
+            ["elves".to_owned(), stats.elves.to_string()],
+            ["median".to_owned(), format!("{:.1}", stats.median_calories)],
+            ["most".to_owned(), most],
+        ],
+    );
+
+    let spread = |label: &str, spread: &Spread| {
+        [
+            label.to_owned(),
+            spread.min.to_string(),
+            spread.max.to_string(),
+            format!("{:.1}", spread.mean),
+        ]
+    };
+    let spreads = align_columns(
+        &["", "min", "max", "mean"],
+        &[
+            Alignment::Left,
+            Alignment::Right,
+            Alignment::Right,
+            Alignment::Right,
+        ],
+        &[
+            spread("items", &stats.items),
+            spread("calories", &stats.calories),
+        ],
+    );
+
+    let biggest = stats.histogram.iter().map(|bucket| bucket.elves).max();
+    let bars: Vec<[String; 3]> = stats
+        .histogram
+        .iter()
+        .map(|bucket| {
+            // Bars scale so the fullest bucket is 40 wide.
+            let bar = bucket.elves * 40 / biggest.unwrap_or(1).max(1);
+            [
+                format!("{}-{}", bucket.from, bucket.to),
+                bucket.elves.to_string(),
+                "#".repeat(bar),
+            ]
+        })
+        .collect();
+    let histogram = align_columns(&[], &[Alignment::Left, Alignment::Right], &bars);
+
+    [summary, spreads, histogram]
+        .into_iter()
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stats, table, Bucket};
+    use crate::{fixtures::EXAMPLE_INPUT, Elves};
+
+    #[test]
+    fn test_example_stats() {
+        let stats = stats(Elves::new(EXAMPLE_INPUT.as_bytes()), 4).unwrap();
+        assert_eq!(stats.elves, 5);
+        assert_eq!((stats.items.min, stats.items.max), (1, 3));
+        assert_eq!(stats.items.mean, 2.0);
+        assert_eq!((stats.calories.min, stats.calories.max), (4000, 24000));
+        assert_eq!(stats.calories.mean, 11000.0);
+        assert_eq!(stats.median_calories, 10000.0);
+        assert_eq!(stats.top, vec![3]);
+        assert_eq!(
+            stats.histogram,
+            vec![
+                Bucket {
+                    from: 4000,
+                    to: 9000,
+                    elves: 2
+                },
+                Bucket {
+                    from: 9001,
+                    to: 14001,
+                    elves: 2
+                },
+                Bucket {
+                    from: 14002,
+                    to: 19002,
+                    elves: 0
+                },
+                Bucket {
+                    from: 19003,
+                    to: 24000,
+                    elves: 1
+                },
+            ]
+        );
+
+        let table = table(&stats);
+        assert!(table.contains("most    #4\n"), "{table}");
+    }
+
+    #[test]
+    fn test_ties_at_the_top() {
+        let stats = stats(Elves::new("5\n\n9\n\n4\n5\n\n1".as_bytes()), 10).unwrap();
+        assert_eq!(stats.top, vec![1, 2]);
+        assert_eq!(stats.median_calories, 7.0);
+        assert_eq!(stats.histogram.len(), 9);
+        assert!(table(&stats).contains("most    #2, #3 (tied)\n"));
+    }
+
+    #[test]
+    fn test_no_elves() {
+        let stats = stats(Elves::new("".as_bytes()), 10).unwrap();
+        assert_eq!(stats.elves, 0);
+        assert!(stats.top.is_empty());
+        assert!(stats.histogram.is_empty());
+        assert_eq!(
+            table(&stats),
+            [
+                "elves   0\n",
+                "median  0.0\n",
+                "most    -\n",
+                "\n",
+                "          min  max  mean\n",
+                "items       0    0   0.0\n",
+                "calories    0    0   0.0\n",
+            ]
+            .concat()
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Which characters are item types, and what each one's priority is.
+
+use std::{collections::HashMap, fmt::Display};
+
+/// The most item types an alphabet can have, one per bit of an
+/// [`ItemSet`](crate::ItemSet).
+pub const MAX_ITEM_TYPES: usize = 64;
+
+/// A priority scheme. Each item type has its own index below
+/// [`MAX_ITEM_TYPES`], which is its bit in an [`ItemSet`](crate::ItemSet),
+/// and a priority, which is what it adds to an answer. Every other character
+/// isn't an item.
+pub trait Alphabet {
+    /// Where `item` comes in the alphabet, counting from 0.
+    fn index(&self, item: char) -> Option<usize>;
+
+    /// The item type at `index`, the inverse of [`Alphabet::index`].
+    fn item(&self, index: usize) -> Option<char>;
+
+    /// The priority of the item type at `index`, by default one more than
+    /// the index.
+    fn priority(&self, index: usize) -> u32 {
+        index as u32 + 1
+    }
+}
+
+/// The puzzle's own scheme: `a` to `z` then `A` to `Z`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ascii;
+
+impl Alphabet for Ascii {
+    fn index(&self, item: char) -> Option<usize> {
+        crate::priority(item).map(|priority| priority as usize - 1)
+    }
+
+    fn item(&self, index: usize) -> Option<char> {
+        crate::item(u32::try_from(index).ok()?.checked_add(1)?)
+    }
+}
+
+/// Any characters at all, in the order given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Custom {
+    items: Vec<char>,
+    priorities: Vec<u32>,
+    indices: HashMap<char, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphabetError {
+    Empty,
+    Repeated(char),
+    TooMany(usize),
+}
+
+impl Display for AlphabetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlphabetError::Empty => write!(f, "an alphabet needs at least one item type"),
+            AlphabetError::Repeated(item) => write!(f, "`{item}` is in the alphabet twice"),
+            AlphabetError::TooMany(items) => write!(
+                f,
+                "{items} item types is more than the {MAX_ITEM_TYPES} an alphabet can have"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AlphabetError {}
+
+impl Custom {
+    /// The alphabet of `items`, the first with priority 1, the next 2 and
+    /// so on.
+    pub fn new(items: &str) -> Result<Self, AlphabetError> {
+        Custom::with_priorities(items.chars().zip(1..))
+    }
+
+    /// The alphabet of each item with its priority, which can be anything at
+    /// all.
+    pub fn with_priorities(
+        items: impl IntoIterator<Item = (char, u32)>,
+    ) -> Result<Self, AlphabetError> {
+        let (items, priorities): (Vec<char>, Vec<u32>) = items.into_iter().unzip();
+        if items.is_empty() {
+            return Err(AlphabetError::Empty);
+        }
+        if items.len() > MAX_ITEM_TYPES {
+            return Err(AlphabetError::TooMany(items.len()));
+        }
+
+        let mut indices = HashMap::with_capacity(items.len());
+        for (index, &item) in items.iter().enumerate() {
+            if indices.insert(item, index).is_some() {
+                return Err(AlphabetError::Repeated(item));
+            }
+        }
+
+        Ok(Custom {
+            items,
+            priorities,
+            indices,
+        })
+    }
+}
+
+impl Alphabet for Custom {
+    fn index(&self, item: char) -> Option<usize> {
+        self.indices.get(&item).copied()
+    }
+
+    fn item(&self, index: usize) -> Option<char> {
+        self.items.get(index).copied()
+    }
+
+    fn priority(&self, index: usize) -> u32 {
+        self.priorities[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alphabet, AlphabetError, Ascii, Custom};
+    use crate::{
+        parse_rucksacks_in, shared_item_priorities, shared_item_priorities_in,
+        validate::validate_in, ItemSet,
+    };
+
+    #[test]
+    fn test_custom() {
+        let greek = Custom::new("αβγδεζ").unwrap();
+        assert_eq!(greek.index('γ'), Some(2));
+        assert_eq!(greek.priority(2), 3);
+        assert_eq!(greek.item(5), Some('ζ'));
+        assert_eq!(greek.index('a'), None);
+        assert_eq!(greek.item(6), None);
+
+        assert_eq!(Custom::new(""), Err(AlphabetError::Empty));
+        assert_eq!(Custom::new("abca"), Err(AlphabetError::Repeated('a')));
+        let too_many: String = ('一'..).take(65).collect();
+        assert_eq!(Custom::new(&too_many), Err(AlphabetError::TooMany(65)));
+        assert!(Custom::new(&too_many[..too_many.len() - 3]).is_ok());
+    }
+
+    #[test]
+    fn test_multibyte_items() {
+        let greek = Custom::new("αβγδεζ").unwrap();
+        // Split in half by bytes, `β` would be cut in two.
+        let rucksacks = parse_rucksacks_in("αβγζβδ\nεεζε", &greek).unwrap();
+        assert_eq!(rucksacks[0].compartments[0], ItemSet::of_in(&greek, "αβγ"));
+        assert_eq!(rucksacks[0].shared().items(&greek).collect::<String>(), "β");
+        assert_eq!(shared_item_priorities_in(&rucksacks, &greek), 2 + 5);
+
+        let error = parse_rucksacks_in("αβγ", &greek).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 3 input line 1 column 1: 3 items can't be split between two compartments"
+        );
+        let error = parse_rucksacks_in("αβγa", &greek).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 3 input line 1 column 4: `a` isn't an item type"
+        );
+    }
+
+    #[test]
+    fn test_reordered_ascii() {
+        let upper_first = Custom::new(&('A'..='Z').chain('a'..='z').collect::<String>()).unwrap();
+        assert_eq!(
+            upper_first
+                .index('A')
+                .map(|index| upper_first.priority(index)),
+            Some(1)
+        );
+        assert_eq!(
+            upper_first
+                .index('a')
+                .map(|index| upper_first.priority(index)),
+            Some(27)
+        );
+
+        let rucksacks = parse_rucksacks_in("aBcaBd", &upper_first).unwrap();
+        assert_eq!(shared_item_priorities_in(&rucksacks, &upper_first), 2 + 27);
+        let rucksacks = parse_rucksacks_in("aBcaBd", &Ascii).unwrap();
+        assert_eq!(shared_item_priorities(&rucksacks), 28 + 1);
+    }
+
+    #[test]
+    fn test_priorities_past_the_set() {
+        // Priorities are only what an item is worth, so they can be anything.
+        let hundreds = Custom::with_priorities(('a'..='z').zip(100..)).unwrap();
+        let rucksacks = parse_rucksacks_in("abcabd\nzzzz", &hundreds).unwrap();
+        assert_eq!(
+            shared_item_priorities_in(&rucksacks, &hundreds),
+            100 + 101 + 125
+        );
+
+        // Indices are bits in an item set, so past 63 they can't be stored.
+        struct Sparse;
+        impl Alphabet for Sparse {
+            fn index(&self, item: char) -> Option<usize> {
+                item.is_ascii_lowercase()
+                    .then(|| (item as usize - 'a' as usize) * 10)
+            }
+
+            fn item(&self, index: usize) -> Option<char> {
+                char::from_u32('a' as u32 + u32::try_from(index / 10).ok()?)
+            }
+        }
+        assert!(parse_rucksacks_in("agag", &Sparse).is_ok());
+        let error = parse_rucksacks_in("ahah", &Sparse).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 3 input line 1 column 2: `h` has index 70, past the 64 item types a set can hold"
+        );
+        assert_eq!(
+            validate_in("ahah", 1, &Sparse)[0].to_string(),
+            "line 1: column 2: `h` has index 70, past the 64 item types a set can hold"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Showing the working behind each answer, a row per rucksack or group.
+
+use crate::{
+    alphabet::{Alphabet, Ascii},
+    badges, parse_rucksacks_in, split_compartments,
+};
+use aoc_core::{table::align, Error};
+use std::{fmt::Write, ops::RangeInclusive};
+
+/// A row of an explanation, as cells under [`Row::HEADER`].
+pub trait Row {
+    const HEADER: &'static [&'static str];
+
+    fn cells(&self) -> Vec<String>;
+}
+
+/// How one rucksack adds to part one's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackRow {
+    pub line: usize,
+    pub compartments: [String; 2],
+    /// The types in both compartments, in alphabet order.
+    pub shared: String,
+    pub priority: usize,
+    /// The answer so far, this rucksack included.
+    pub total: usize,
+}
+
+impl Row for RucksackRow {
+    const HEADER: &'static [&'static str] =
+        &["line", "first", "second", "shared", "priority", "total"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.line.to_string(),
+            self.compartments[0].clone(),
+            self.compartments[1].clone(),
+            self.shared.clone(),
+            self.priority.to_string(),
+            self.total.to_string(),
+        ]
+    }
+}
+
+/// How one group adds to part two's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupRow {
+    /// The lines of the group's rucksacks, counting from 1.
+    pub lines: RangeInclusive<usize>,
+    /// The types the whole group carries, in alphabet order.
+    pub badge: String,
+    pub priority: usize,
+    /// The answer so far, this group included.
+    pub total: usize,
+}
+
+impl Row for GroupRow {
+    const HEADER: &'static [&'static str] = &["lines", "badge", "priority", "total"];
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            format!("{}-{}", self.lines.start(), self.lines.end()),
+            self.badge.clone(),
+            self.priority.to_string(),
+            self.total.to_string(),
+        ]
+    }
+}
+
+/// Part one's answer built up a rucksack at a time.
+pub fn explain_part_1(input: &str) -> Result<Vec<RucksackRow>, Error> {
+    explain_part_1_in(input, &Ascii)
+}
+
+/// [`explain_part_1`] with the item types of `alphabet`.
+pub fn explain_part_1_in<A: Alphabet + ?Sized>(
+    input: &str,
+    alphabet: &A,
+) -> Result<Vec<RucksackRow>, Error> {
+    let rucksacks = parse_rucksacks_in(input, alphabet)?;
+    let mut total = 0;
+
+    Ok(input
+        .lines()
+        .zip(rucksacks)
+        .enumerate()
+        .map(|(idx, (line, rucksack))| {
+            let (first, second) = split_compartments(line).expect("it parsed");
+            let shared = rucksack.shared();
+            total += shared.priority_sum_in(alphabet);
+
+            RucksackRow {
+                line: idx + 1,
+                compartments: [first.to_owned(), second.to_owned()],
+                shared: shared.items(alphabet).collect(),
+                priority: shared.priority_sum_in(alphabet),
+                total,
+            }
+        })
+        .collect())
+}
+
+/// Part two's answer built up a group of `group_size` at a time.
+pub fn explain_part_2(input: &str, group_size: usize) -> Result<Vec<GroupRow>, Error> {
+    explain_part_2_in(input, group_size, &Ascii)
+}
+
+/// [`explain_part_2`] with the item types of `alphabet`.
+pub fn explain_part_2_in<A: Alphabet + ?Sized>(
+    input: &str,
+    group_size: usize,
+    alphabet: &A,
+) -> Result<Vec<GroupRow>, Error> {
+    let rucksacks = parse_rucksacks_in(input, alphabet)?;
+    let mut total = 0;
+
+    Ok(badges(&rucksacks, group_size)
+        .enumerate()
+        .map(|(idx, badge)| {
+            total += badge.priority_sum_in(alphabet);
+
+            GroupRow {
+                lines: idx * group_size + 1..=(idx + 1) * group_size,
+                badge: badge.items(alphabet).collect(),
+                priority: badge.priority_sum_in(alphabet),
+                total,
+            }
+        })
+        .collect())
+}
+
+/// Lays the rows out in left aligned columns.
+pub fn table<R: Row>(rows: &[R]) -> String {
+    let rows: Vec<Vec<String>> = rows.iter().map(Row::cells).collect();
+    align(R::HEADER, &rows)
+}
+
+/// The rows as CSV with a header. Only a cell holding items from an
+/// alphabet with `,` or `"` in it needs quoting.
+pub fn csv<R: Row>(rows: &[R]) -> String {
+    let mut out = R::HEADER.join(",") + "\n";
+    for row in rows {
+        let cells: Vec<String> = row.cells().iter().map(|cell| quote(cell)).collect();
+        writeln!(out, "{}", cells.join(",")).unwrap();
+    }
+
+    out
+}
+
+fn quote(cell: &str) -> String {
+    match cell.contains([',', '"']) {
+        true => format!("\"{}\"", cell.replace('"', "\"\"")),
+        false => cell.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv, explain_part_1, explain_part_1_in, explain_part_2, explain_part_2_in, table};
+    use crate::{alphabet::Custom, fixtures::EXAMPLE_INPUT};
+    use aoc_core::{answers::expected, Part};
+
+    #[test]
+    fn test_explain_part_1() {
+        let rows = explain_part_1(EXAMPLE_INPUT).unwrap();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows.last().unwrap().total,
+            expected(3, "example", Part::One)
+        );
+
+        let table = table(&rows);
+        assert!(table.starts_with("line  first"), "{table}");
+        assert!(
+            table.contains("1     vJrwpWtwJgWr      hcsFMMfFFhFp      p       16        16\n"),
+            "{table}"
+        );
+
+        let csv = csv(&rows);
+        assert!(csv.starts_with("line,first,second,shared,priority,total\n"));
+        assert!(csv.ends_with("6,CrZsJsPPZsGz,wwsLwLmpwMDw,s,19,157\n"));
+    }
+
+    #[test]
+    fn test_explain_part_2() {
+        let rows = explain_part_2(EXAMPLE_INPUT, 3).unwrap();
+        assert_eq!(
+            csv(&rows),
+            "lines,badge,priority,total\n1-3,r,18,18\n4-6,Z,52,70\n"
+        );
+        assert_eq!(
+            rows.last().unwrap().total,
+            expected(3, "example", Part::Two)
+        );
+
+        assert!(explain_part_2("ab\nc", 2).is_err());
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let greek = Custom::new("αβγδεζ").unwrap();
+        let input = "αβγβ\nδαεζαε";
+
+        let rows = explain_part_1_in(input, &greek).unwrap();
+        assert_eq!(
+            csv(&rows),
+            "line,first,second,shared,priority,total\n1,αβ,γβ,β,2,2\n2,δαε,ζαε,αε,6,8\n"
+        );
+        let rows = explain_part_2_in(input, 2, &greek).unwrap();
+        assert_eq!(csv(&rows), "lines,badge,priority,total\n1-2,α,1,1\n");
+        assert!(explain_part_1("αβγβ").is_err());
+
+        let punctuation = Custom::new(",\"").unwrap();
+        let rows = explain_part_1_in(",\",\"", &punctuation).unwrap();
+        assert!(csv(&rows).ends_with("1,\",\"\"\",\",\"\"\",\",\"\"\",3,3\n"));
+
+        let hundreds = Custom::with_priorities("αβγδεζ".chars().zip(100..)).unwrap();
+        let rows = explain_part_1_in(input, &hundreds).unwrap();
+        assert_eq!(
+            rows.iter().map(|row| row.priority).collect::<Vec<_>>(),
+            vec![101, 100 + 104]
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use alphabet::{Alphabet, Ascii, MAX_ITEM_TYPES};
+use aoc_core::{Error, Solution};
+use std::fmt::Display;
+
+pub mod alphabet;
+pub mod explain;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod validate;
+
+/// The priority of an item type: `a` to `z` are 1 to 26, `A` to `Z` 27 to 52.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item type with `priority`, the inverse of [`priority`].
+pub fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, one bit per index in the [`Alphabet`] they're from,
+/// so it only means anything alongside that alphabet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Every item type in `items`, leaving out anything that isn't one.
+    pub fn of(items: &str) -> Self {
+        ItemSet::of_in(&Ascii, items)
+    }
+
+    /// [`ItemSet::of`] with the item types of `alphabet`. An index too big
+    /// for the set is left out too, which [`Rucksack::parse_in`] refuses.
+    pub fn of_in<A: Alphabet + ?Sized>(alphabet: &A, items: &str) -> Self {
+        ItemSet(
+            items
+                .chars()
+                .filter_map(|item| alphabet.index(item))
+                .filter(|&index| index < MAX_ITEM_TYPES)
+                .fold(0, |bits, index| bits | 1 << index),
+        )
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The index of each item type in the set, lowest first.
+    pub fn indices(self) -> impl Iterator<Item = usize> {
+        (0..MAX_ITEM_TYPES).filter(move |index| self.0 & 1 << index != 0)
+    }
+
+    /// The priority of each item type in the set, lowest index first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        self.priorities_in(&Ascii)
+    }
+
+    /// [`ItemSet::priorities`] as `alphabet` gives them.
+    pub fn priorities_in<A: Alphabet + ?Sized>(
+        self,
+        alphabet: &A,
+    ) -> impl Iterator<Item = u32> + '_ {
+        self.indices().map(move |index| alphabet.priority(index))
+    }
+
+    /// The item types in the set as `alphabet` has them, lowest index first.
+    pub fn items<A: Alphabet + ?Sized>(self, alphabet: &A) -> impl Iterator<Item = char> + '_ {
+        self.indices().filter_map(move |index| alphabet.item(index))
+    }
+
+    pub fn priority_sum(self) -> usize {
+        self.priority_sum_in(&Ascii)
+    }
+
+    /// [`ItemSet::priority_sum`] with the priorities of `alphabet`.
+    pub fn priority_sum_in<A: Alphabet + ?Sized>(self, alphabet: &A) -> usize {
+        self.priorities_in(alphabet)
+            .map(|priority| priority as usize)
+            .sum()
+    }
+}
+
+/// The item types in the puzzle's own alphabet, in alphabet order.
+impl Display for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.items(&Ascii).try_for_each(|item| write!(f, "{item}"))
+    }
+}
+
+/// `items` split into its two compartments, the same number of items in
+/// each, or `None` when there's an odd number.
+pub fn split_compartments(items: &str) -> Option<(&str, &str)> {
+    let count = items.chars().count();
+    if !count.is_multiple_of(2) {
+        return None;
+    }
+
+    let middle = items
+        .char_indices()
+        .nth(count / 2)
+        .map_or(items.len(), |(index, _)| index);
+    Some(items.split_at(middle))
+}
+
+/// The item types in each compartment of a rucksack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: [ItemSet; 2],
+}
+
+impl Rucksack {
+    /// The rucksack packed with `items`, from `line` of the input.
+    pub fn parse(line: usize, items: &str) -> Result<Rucksack, Error> {
+        Rucksack::parse_in(&Ascii, line, items)
+    }
+
+    /// [`Rucksack::parse`] with the item types of `alphabet`.
+    pub fn parse_in<A: Alphabet + ?Sized>(
+        alphabet: &A,
+        line: usize,
+        items: &str,
+    ) -> Result<Rucksack, Error> {
+        // Otherwise it'd be an empty rucksack, throwing every group after it
+        // out by one.
+        if items.is_empty() {
+            return Err(Error::parse(3, line, 1, "a blank line isn't a rucksack"));
+        }
+        for (column, item) in items.chars().enumerate() {
+            if let Err(message) = index_of(alphabet, item) {
+                return Err(Error::parse(3, line, column + 1, message));
+            }
+        }
+        let Some((first, second)) = split_compartments(items) else {
+            return Err(Error::parse(
+                3,
+                line,
+                1,
+                format!(
+                    "{} items can't be split between two compartments",
+                    items.chars().count()
+                ),
+            ));
+        };
+
+        Ok(Rucksack {
+            compartments: [
+                ItemSet::of_in(alphabet, first),
+                ItemSet::of_in(alphabet, second),
+            ],
+        })
+    }
+
+    /// Types packed in both compartments.
+    pub fn shared(&self) -> ItemSet {
+        self.compartments[0].intersection(self.compartments[1])
+    }
+
+    /// Types packed anywhere in the rucksack.
+    pub fn items(&self) -> ItemSet {
+        self.compartments[0].union(self.compartments[1])
+    }
+}
+
+/// Where `item` comes in `alphabet`, or why it can't go in an [`ItemSet`].
+pub(crate) fn index_of<A: Alphabet + ?Sized>(alphabet: &A, item: char) -> Result<usize, String> {
+    match alphabet.index(item) {
+        None => Err(format!("`{item}` isn't an item type")),
+        Some(index) if index >= MAX_ITEM_TYPES => Err(format!(
+            "`{item}` has index {index}, past the {MAX_ITEM_TYPES} item types a set can hold"
+        )),
+        Some(index) => Ok(index),
+    }
+}
+
+/// How many elves share a badge in the puzzle as given.
+pub const GROUP_SIZE: usize = 3;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Parsed<'a> = Vec<Rucksack>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    #[cfg(not(feature = "parallel"))]
+    fn parse(input: &str) -> Result<Vec<Rucksack>, Error> {
+        parse_rucksacks(input)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn parse(input: &str) -> Result<Vec<Rucksack>, Error> {
+        parallel::parse(input)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part_one(rucksacks: &Vec<Rucksack>) -> Result<usize, Error> {
+        Ok(shared_item_priorities(rucksacks))
+    }
+
+    #[cfg(feature = "parallel")]
+    fn part_one(rucksacks: &Vec<Rucksack>) -> Result<usize, Error> {
+        Ok(parallel::shared_item_priorities(rucksacks))
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn part_two(rucksacks: &Vec<Rucksack>) -> Result<usize, Error> {
+        Ok(badge_priorities(rucksacks, GROUP_SIZE))
+    }
+
+    #[cfg(feature = "parallel")]
+    fn part_two(rucksacks: &Vec<Rucksack>) -> Result<usize, Error> {
+        Ok(parallel::badge_priorities(rucksacks, GROUP_SIZE))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    Day3::part_one(&Day3::parse(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    Day3::part_two(&Day3::parse(input)?)
+}
+
+/// Every rucksack in `input`, a line at a time.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, Error> {
+    parse_rucksacks_in(input, &Ascii)
+}
+
+/// [`parse_rucksacks`] with the item types of `alphabet`.
+pub fn parse_rucksacks_in<A: Alphabet + ?Sized>(
+    input: &str,
+    alphabet: &A,
+) -> Result<Vec<Rucksack>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, items)| Rucksack::parse_in(alphabet, idx + 1, items))
+        .collect()
+}
+
+/// The sum of the priorities of the types in both compartments of each
+/// rucksack.
+///
+/// Every shared type counts, where the original solution only counted the
+/// first one it came across. The puzzle promises exactly one, so that only
+/// changes the answer for inputs [`validate::validate`] complains about.
+pub fn shared_item_priorities(rucksacks: &[Rucksack]) -> usize {
+    shared_item_priorities_in(rucksacks, &Ascii)
+}
+
+/// [`shared_item_priorities`] with the priorities of `alphabet`.
+pub fn shared_item_priorities_in<A: Alphabet + ?Sized>(
+    rucksacks: &[Rucksack],
+    alphabet: &A,
+) -> usize {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.shared().priority_sum_in(alphabet))
+        .sum()
+}
+
+/// The types every elf carries in each run of `group_size` rucksacks, which
+/// should be just the group's badge. Rucksacks left over after the last full
+/// group are ignored.
+///
+/// # Panics
+///
+/// If `group_size` is zero.
+pub fn badges(rucksacks: &[Rucksack], group_size: usize) -> impl Iterator<Item = ItemSet> + '_ {
+    rucksacks.chunks_exact(group_size).map(|group| {
+        group
+            .iter()
+            .map(Rucksack::items)
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default()
+    })
+}
+
+/// The sum of every badge's priority with elves in groups of `group_size`.
+///
+/// Like [`shared_item_priorities`], a group carrying more than one type in
+/// common adds every one of them.
+pub fn badge_priorities(rucksacks: &[Rucksack], group_size: usize) -> usize {
+    badge_priorities_in(rucksacks, group_size, &Ascii)
+}
+
+/// [`badge_priorities`] with the priorities of `alphabet`.
+pub fn badge_priorities_in<A: Alphabet + ?Sized>(
+    rucksacks: &[Rucksack],
+    group_size: usize,
+    alphabet: &A,
+) -> usize {
+    badges(rucksacks, group_size)
+        .map(|badge| badge.priority_sum_in(alphabet))
+        .sum()
+}
+
+#[cfg(test)]
+mod fixtures {
+    pub const EXAMPLE_INPUT: &str = include_str!("../../inputs/2022/day-3/example.txt");
+    pub const ACTUAL_INPUT: &str = include_str!("../../inputs/2022/day-3/actual.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        badge_priorities, badges,
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+        item, priority, shared_item_priorities, solve_part_1, solve_part_2, Day3, ItemSet,
+    };
+    use aoc_core::{answers::expected, Part, Solution};
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            solve_part_1(EXAMPLE_INPUT),
+            Ok(expected(3, "example", Part::One))
+        );
+        assert_eq!(
+            solve_part_1(ACTUAL_INPUT),
+            Ok(expected(3, "actual", Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            solve_part_2(EXAMPLE_INPUT),
+            Ok(expected(3, "example", Part::Two))
+        );
+        assert_eq!(
+            solve_part_2(ACTUAL_INPUT),
+            Ok(expected(3, "actual", Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_newline_terminated() {
+        for input in [EXAMPLE_INPUT, ACTUAL_INPUT] {
+            for ending in ["\n", "\r\n"] {
+                let terminated = format!("{input}{ending}");
+                assert_eq!(solve_part_1(&terminated), solve_part_1(input));
+                assert_eq!(solve_part_2(&terminated), solve_part_2(input));
+            }
+        }
+
+        let error = Day3::parse(&format!("{EXAMPLE_INPUT}\n\n")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "day 3 input line 7 column 1: a blank line isn't a rucksack"
+        );
+        assert!(Day3::parse("abab\n\ncdcd\n").is_err());
+    }
+
+    #[test]
+    fn test_item_set() {
+        assert_eq!(priority('a'), Some(1));
+        assert_eq!(priority('Z'), Some(52));
+        assert_eq!(priority('1'), None);
+
+        let left = ItemSet::of("vJrwpWtwJgWr");
+        let right = ItemSet::of("hcsFMMfFFhFp");
+        assert_eq!(left.len(), 8);
+        assert_eq!(left.intersection(right), ItemSet::of("p"));
+        assert_eq!(left.intersection(right).priority_sum(), 16);
+        assert_eq!(left.union(right).len(), 14);
+        assert!(ItemSet::of("abc")
+            .intersection(ItemSet::of("ABC"))
+            .is_empty());
+        assert_eq!(
+            ItemSet::of("zaZA").priorities().collect::<Vec<_>>(),
+            vec![1, 26, 27, 52]
+        );
+        assert_eq!(ItemSet::of("ZzAa").to_string(), "azAZ");
+        assert!((1..=52).all(|p| item(p).and_then(priority) == Some(p)));
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        let rucksacks = Day3::parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            badges(&rucksacks, 3).collect::<Vec<_>>(),
+            vec![ItemSet::of("r"), ItemSet::of("Z")]
+        );
+        assert_eq!(badge_priorities(&rucksacks, 3), 70);
+
+        // Pairs have more than one type in common, and the last rucksack has
+        // no pair.
+        let rucksacks = Day3::parse("abcd\nbcde\nxyzz\nyzxw\nAB").unwrap();
+        assert_eq!(
+            badges(&rucksacks, 2).collect::<Vec<_>>(),
+            vec![ItemSet::of("bcd"), ItemSet::of("xyz")]
+        );
+        assert_eq!(badge_priorities(&rucksacks, 2), 2 + 3 + 4 + 24 + 25 + 26);
+        assert_eq!(badge_priorities(&rucksacks, 6), 0);
+        assert_eq!(
+            badge_priorities(&rucksacks, 1),
+            rucksacks.iter().map(|r| r.items().priority_sum()).sum()
+        );
+    }
+
+    #[test]
+    fn test_every_shared_type_counts() {
+        // `b` comes before `a` in the first compartment, so counting only the
+        // first shared type found would give 2 rather than 1 + 2.
+        let rucksacks = Day3::parse("bacbaa").unwrap();
+        assert_eq!(rucksacks[0].shared(), ItemSet::of("ab"));
+        assert_eq!(shared_item_priorities(&rucksacks), 1 + 2);
+        assert_eq!(Day3::part_one(&rucksacks), Ok(1 + 2));
+
+        // Likewise the whole group carries both `x` and `y`, where only the
+        // first elf's `y` would have counted.
+        let rucksacks = Day3::parse("yxyx\nxyab\nyxcx").unwrap();
+        assert_eq!(badge_priorities(&rucksacks, 3), 24 + 25);
+        assert_eq!(Day3::part_two(&rucksacks), Ok(24 + 25));
+    }
+}
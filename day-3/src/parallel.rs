@@ -0,0 +1,135 @@
+//! Parsing and summing across every core, for inputs with millions of
+//! rucksacks. [`crate::Day3`] uses these in place of the sequential versions
+//! with the `parallel` feature on.
+
+use crate::Rucksack;
+use aoc_core::Error;
+use rayon::prelude::*;
+
+/// Roughly how many rucksacks each task gets.
+const CHUNK: usize = 1 << 16;
+
+/// [`crate::parse_rucksacks`] with the lines split between tasks.
+pub fn parse(input: &str) -> Result<Vec<Rucksack>, Error> {
+    parse_in_chunks(input, CHUNK)
+}
+
+fn parse_in_chunks(input: &str, chunk: usize) -> Result<Vec<Rucksack>, Error> {
+    let lines: Vec<&str> = input.lines().collect();
+    let chunks: Vec<Result<Vec<Rucksack>, Error>> = lines
+        .par_chunks(chunk)
+        .enumerate()
+        .map(|(n, lines)| {
+            lines
+                .iter()
+                .enumerate()
+                .map(|(idx, items)| Rucksack::parse(n * chunk + idx + 1, items))
+                .collect()
+        })
+        .collect();
+
+    // The chunks come back in order, so the first error is the same one
+    // parsing a line at a time stops at.
+    let mut rucksacks = Vec::with_capacity(lines.len());
+    for chunk in chunks {
+        rucksacks.extend(chunk?);
+    }
+
+    Ok(rucksacks)
+}
+
+/// [`crate::shared_item_priorities`] with the rucksacks split between tasks.
+pub fn shared_item_priorities(rucksacks: &[Rucksack]) -> usize {
+    shared_item_priorities_in_chunks(rucksacks, CHUNK)
+}
+
+fn shared_item_priorities_in_chunks(rucksacks: &[Rucksack], chunk: usize) -> usize {
+    rucksacks
+        .par_chunks(chunk)
+        .map(crate::shared_item_priorities)
+        .sum()
+}
+
+/// [`crate::badge_priorities`] with the rucksacks split between tasks.
+pub fn badge_priorities(rucksacks: &[Rucksack], group_size: usize) -> usize {
+    badge_priorities_in_chunks(rucksacks, group_size, CHUNK)
+}
+
+fn badge_priorities_in_chunks(rucksacks: &[Rucksack], group_size: usize, chunk: usize) -> usize {
+    // Only whole groups go in a chunk, so none is split between two and only
+    // the last chunk can have rucksacks left over.
+    let chunk = (chunk / group_size).max(1) * group_size;
+    rucksacks
+        .par_chunks(chunk)
+        .map(|rucksacks| crate::badge_priorities(rucksacks, group_size))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{badge_priorities_in_chunks, parse_in_chunks, shared_item_priorities_in_chunks};
+    use crate::{
+        badge_priorities, fixtures::ACTUAL_INPUT, parse_rucksacks, shared_item_priorities,
+    };
+
+    /// A few thousand made up rucksacks, the same every run.
+    fn stress_input() -> String {
+        let mut seed: u64 = 3;
+        let mut next = move |below: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % below
+        };
+
+        (0..5000)
+            .map(|_| {
+                let len = 2 * (1 + next(20) as usize);
+                (0..len)
+                    .map(|_| crate::item(1 + next(52) as u32).unwrap())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_same_as_sequential() {
+        for input in [ACTUAL_INPUT.to_owned(), stress_input()] {
+            let sequential = parse_rucksacks(&input).unwrap();
+            for chunk in [1, 7, 64, 1 << 16] {
+                let parallel = parse_in_chunks(&input, chunk).unwrap();
+                assert_eq!(parallel, sequential);
+                assert_eq!(
+                    shared_item_priorities_in_chunks(&parallel, chunk),
+                    shared_item_priorities(&sequential)
+                );
+
+                for group_size in [2, 3, 5] {
+                    assert_eq!(
+                        badge_priorities_in_chunks(&parallel, group_size, chunk),
+                        badge_priorities(&sequential, group_size),
+                        "chunks of {chunk}, groups of {group_size}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_first_error() {
+        let mut lines: Vec<String> = stress_input().lines().map(String::from).collect();
+        lines[40].push('!');
+        lines[3000].push('x');
+        let input = lines.join("\n");
+
+        let error = parse_rucksacks(&input).unwrap_err();
+        assert!(
+            error.to_string().starts_with("day 3 input line 41 "),
+            "{error}"
+        );
+        for chunk in [1, 7, 64, 1 << 16] {
+            assert_eq!(parse_in_chunks(&input, chunk), Err(error.clone()));
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Checking the input holds to what the puzzle promises, rather than stopping
+//! at the first thing that doesn't.
+
+use crate::{
+    alphabet::{Alphabet, Ascii, MAX_ITEM_TYPES},
+    split_compartments, ItemSet,
+};
+use std::{fmt::Display, ops::RangeInclusive};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// A character that isn't an item type, at `column`.
+    UnknownItem {
+        column: usize,
+        item: char,
+    },
+    /// An item type whose index in the alphabet is too big for an
+    /// [`ItemSet`], at `column`.
+    IndexTooBig {
+        column: usize,
+        item: char,
+        index: usize,
+    },
+    /// The items can't be split evenly between the two compartments.
+    OddLength {
+        items: usize,
+    },
+    NoSharedItem,
+    /// More than the one type is packed in both compartments, written out
+    /// in alphabet order.
+    SharedItems(String),
+    NoBadge,
+    /// More than the one type is carried by every elf in the group, written
+    /// out in alphabet order.
+    Badges(String),
+    /// The input ends partway through a group, with only `elves` in the last.
+    IncompleteGroup {
+        elves: usize,
+    },
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::UnknownItem { column, item } => {
+                write!(f, "column {column}: `{item}` isn't an item type")
+            }
+            Problem::IndexTooBig {
+                column,
+                item,
+                index,
+            } => write!(
+                f,
+                "column {column}: `{item}` has index {index}, past the {MAX_ITEM_TYPES} item types a set can hold"
+            ),
+            Problem::OddLength { items } => {
+                write!(f, "{items} items can't be split between two compartments")
+            }
+            Problem::NoSharedItem => write!(f, "no item type is in both compartments"),
+            Problem::SharedItems(items) => {
+                write!(
+                    f,
+                    "{} item types are in both compartments: {items}",
+                    items.chars().count()
+                )
+            }
+            Problem::NoBadge => write!(f, "no item type is carried by the whole group"),
+            Problem::Badges(items) => write!(
+                f,
+                "{} item types are carried by the whole group: {items}",
+                items.chars().count()
+            ),
+            Problem::IncompleteGroup { .. } => write!(f, "input ends partway through a group"),
+        }
+    }
+}
+
+/// A problem with one rucksack, or with a group of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The lines of the rucksacks involved, counting from 1.
+    pub lines: RangeInclusive<usize>,
+    pub problem: Problem,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.lines.start(), self.lines.end()) {
+            (start, end) if start == end => write!(f, "line {start}: {}", self.problem),
+            (start, end) => write!(f, "lines {start}-{end}: {}", self.problem),
+        }
+    }
+}
+
+/// Every way `input` breaks the puzzle's rules, with elves in groups of
+/// `group_size`, in input order with each group after its rucksacks.
+///
+/// # Panics
+///
+/// If `group_size` is zero.
+pub fn validate(input: &str, group_size: usize) -> Vec<Diagnostic> {
+    validate_in(input, group_size, &Ascii)
+}
+
+/// [`validate`] with the item types of `alphabet`.
+///
+/// # Panics
+///
+/// If `group_size` is zero.
+pub fn validate_in<A: Alphabet + ?Sized>(
+    input: &str,
+    group_size: usize,
+    alphabet: &A,
+) -> Vec<Diagnostic> {
+    let rucksacks: Vec<&str> = input.lines().collect();
+    let mut diagnostics = Vec::new();
+
+    for (start, group) in (0..).step_by(group_size).zip(rucksacks.chunks(group_size)) {
+        for (offset, rucksack) in group.iter().enumerate() {
+            let line = start + offset + 1;
+            diagnostics.extend(
+                check_rucksack(alphabet, rucksack)
+                    .into_iter()
+                    .map(|problem| Diagnostic {
+                        lines: line..=line,
+                        problem,
+                    }),
+            );
+        }
+
+        let badges = group
+            .iter()
+            .map(|rucksack| ItemSet::of_in(alphabet, rucksack))
+            .reduce(ItemSet::intersection)
+            .unwrap_or_default();
+        let problem = match badges.len() {
+            _ if group.len() < group_size => Problem::IncompleteGroup { elves: group.len() },
+            0 => Problem::NoBadge,
+            1 => continue,
+            _ => Problem::Badges(badges.items(alphabet).collect()),
+        };
+        diagnostics.push(Diagnostic {
+            lines: start + 1..=start + group.len(),
+            problem,
+        });
+    }
+
+    diagnostics
+}
+
+fn check_rucksack<A: Alphabet + ?Sized>(alphabet: &A, rucksack: &str) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = rucksack
+        .chars()
+        .zip(1..)
+        .filter_map(|(item, column)| match alphabet.index(item) {
+            None => Some(Problem::UnknownItem { column, item }),
+            Some(index) if index >= MAX_ITEM_TYPES => Some(Problem::IndexTooBig {
+                column,
+                item,
+                index,
+            }),
+            Some(_) => None,
+        })
+        .collect();
+
+    let Some((first, second)) = split_compartments(rucksack) else {
+        let items = rucksack.chars().count();
+        problems.push(Problem::OddLength { items });
+        return problems;
+    };
+    let shared = ItemSet::of_in(alphabet, first).intersection(ItemSet::of_in(alphabet, second));
+    match shared.len() {
+        0 => problems.push(Problem::NoSharedItem),
+        1 => {}
+        _ => problems.push(Problem::SharedItems(shared.items(alphabet).collect())),
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{validate, validate_in, Problem};
+    use crate::{
+        alphabet::Custom,
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+    };
+
+    #[test]
+    fn test_puzzle_inputs_are_valid() {
+        assert_eq!(validate(EXAMPLE_INPUT, 3), vec![]);
+        assert_eq!(validate(ACTUAL_INPUT, 3), vec![]);
+    }
+
+    #[test]
+    fn test_problems() {
+        let input = "abca\nab1a\nabc\n\
+            abAB\nxyxy\nxzwz\n\
+            aa";
+        let diagnostics = validate(input, 3);
+        let lines: Vec<String> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "line 2: column 3: `1` isn't an item type",
+                "line 3: 3 items can't be split between two compartments",
+                "lines 1-3: 2 item types are carried by the whole group: ab",
+                "line 4: no item type is in both compartments",
+                "line 5: 2 item types are in both compartments: xy",
+                "lines 4-6: no item type is carried by the whole group",
+                "line 7: input ends partway through a group",
+            ]
+        );
+        assert_eq!(
+            diagnostics[4].problem,
+            Problem::SharedItems("xy".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_custom_alphabet() {
+        let greek = Custom::new("αβγδεζ").unwrap();
+        let input = "αββα\nγαδαx\nβγβγ";
+        let lines: Vec<String> = validate_in(input, 3, &greek)
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "line 1: 2 item types are in both compartments: αβ",
+                "line 2: column 5: `x` isn't an item type",
+                "line 2: 5 items can't be split between two compartments",
+                "line 3: 2 item types are in both compartments: βγ",
+                "lines 1-3: no item type is carried by the whole group",
+            ]
+        );
+        assert_eq!(validate_in("αβγα\nαδεα", 2, &greek), vec![]);
+    }
+}
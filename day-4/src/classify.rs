@@ -0,0 +1,169 @@
+//! Sorting every pair by how their ranges meet, to see where the answers
+//! come from.
+
+use crate::{Assignment, SectionRange};
+use aoc_core::table::{align_columns, Alignment};
+use std::fmt::{Display, Write};
+
+/// How a pair's ranges meet. Every pair is exactly one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Class {
+    /// At least one section lies between the ranges.
+    Disjoint,
+    /// No section is in both, but one range ends right before the other
+    /// starts.
+    Touching,
+    /// Some sections are in both, but each range has some of its own.
+    Partial,
+    /// One range is entirely within the other, which is longer.
+    Contains,
+    Identical,
+}
+
+impl Class {
+    pub const ALL: [Class; 5] = [
+        Class::Disjoint,
+        Class::Touching,
+        Class::Partial,
+        Class::Contains,
+        Class::Identical,
+    ];
+
+    /// Whether part one counts the pair.
+    pub fn fully_contained(self) -> bool {
+        matches!(self, Class::Contains | Class::Identical)
+    }
+
+    /// Whether part two counts the pair.
+    pub fn overlapping(self) -> bool {
+        matches!(self, Class::Partial | Class::Contains | Class::Identical)
+    }
+}
+
+impl Display for Class {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Class::Disjoint => "disjoint",
+            Class::Touching => "touching",
+            Class::Partial => "partial",
+            Class::Contains => "contains",
+            Class::Identical => "identical",
+        };
+        f.pad(name)
+    }
+}
+
+impl Assignment {
+    pub fn class(self) -> Class {
+        let Assignment { first, second } = self;
+        if first == second {
+            Class::Identical
+        } else if self.fully_contained() {
+            Class::Contains
+        } else if first.overlaps(second) {
+            Class::Partial
+        } else if adjacent(first, second) {
+            Class::Touching
+        } else {
+            Class::Disjoint
+        }
+    }
+}
+
+fn adjacent(first: SectionRange, second: SectionRange) -> bool {
+    first.end().checked_add(1) == Some(second.start())
+        || second.end().checked_add(1) == Some(first.start())
+}
+
+/// How many of `pairs` fall in each class, in the order of [`Class::ALL`].
+pub fn counts(pairs: &[Assignment]) -> [(Class, usize); 5] {
+    let mut counts = Class::ALL.map(|class| (class, 0));
+    for pair in pairs {
+        counts[pair.class() as usize].1 += 1;
+    }
+
+    counts
+}
+
+/// Lays the counts out with the two answers they add up to.
+pub fn table(counts: &[(Class, usize); 5]) -> String {
+    let rows: Vec<[String; 2]> = counts
+        .iter()
+        .map(|(class, count)| [class.to_string(), count.to_string()])
+        .collect();
+    let mut out = align_columns(&[], &[Alignment::Left, Alignment::Right], &rows);
+
+    for (part, counted) in [
+        (1, Class::fully_contained as fn(Class) -> bool),
+        (2, Class::overlapping),
+    ] {
+        let counted: Vec<&(Class, usize)> =
+            counts.iter().filter(|(class, _)| counted(*class)).collect();
+        let classes: Vec<String> = counted.iter().map(|(class, _)| class.to_string()).collect();
+        let total: usize = counted.iter().map(|(_, count)| count).sum();
+        writeln!(out, "part {part} = {} = {total}", classes.join(" + ")).unwrap();
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{counts, table, Class};
+    use crate::{
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+        Assignment, Day4,
+    };
+    use aoc_core::{answers::expected, Part, Solution};
+
+    #[test]
+    fn test_classes() {
+        let class = |pair: &str| pair.parse::<Assignment>().unwrap().class();
+
+        assert_eq!(class("2-4,6-8"), Class::Disjoint);
+        assert_eq!(class("2-3,4-5"), Class::Touching);
+        assert_eq!(class("4-5,2-3"), Class::Touching);
+        assert_eq!(class("5-7,7-9"), Class::Partial);
+        assert_eq!(class("2-8,3-7"), Class::Contains);
+        assert_eq!(class("6-6,4-6"), Class::Contains);
+        assert_eq!(class("3-7,3-7"), Class::Identical);
+        assert_eq!(class("0-4294967295,4294967295-4294967295"), Class::Contains);
+    }
+
+    #[test]
+    fn test_counts_add_up_to_the_answers() {
+        let pairs = Day4::parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(
+            counts(&pairs),
+            [
+                (Class::Disjoint, 1),
+                (Class::Touching, 1),
+                (Class::Partial, 2),
+                (Class::Contains, 2),
+                (Class::Identical, 0),
+            ]
+        );
+        let table = table(&counts(&pairs));
+        assert!(table.starts_with("disjoint   1\ntouching   1\n"), "{table}");
+        assert!(table.ends_with(
+            "part 1 = contains + identical = 2\n\
+             part 2 = partial + contains + identical = 4\n"
+        ));
+
+        let pairs = Day4::parse(ACTUAL_INPUT).unwrap();
+        let counts = counts(&pairs);
+        let total = |counted: fn(Class) -> bool| {
+            counts
+                .iter()
+                .filter(|(class, _)| counted(*class))
+                .map(|(_, count)| count)
+                .sum::<usize>()
+        };
+        assert_eq!(
+            total(Class::fully_contained),
+            expected(4, "actual", Part::One)
+        );
+        assert_eq!(total(Class::overlapping), expected(4, "actual", Part::Two));
+        assert_eq!(total(|_| true), pairs.len());
+    }
+}
@@ -0,0 +1,361 @@
+use aoc_core::{Error, Solution};
+use std::{fmt::Display, str::FromStr};
+
+pub mod classify;
+
+/// The sections an elf is assigned, from `start` to `end` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SectionRange {
+    start: u32,
+    end: u32,
+}
+
+impl SectionRange {
+    /// The range from `start` to `end`, or `None` if it runs backwards.
+    pub fn new(start: u32, end: u32) -> Option<Self> {
+        (start <= end).then_some(SectionRange { start, end })
+    }
+
+    pub fn start(self) -> u32 {
+        self.start
+    }
+
+    pub fn end(self) -> u32 {
+        self.end
+    }
+
+    /// How many sections there are, never zero.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(self) -> u64 {
+        u64::from(self.end - self.start) + 1
+    }
+
+    pub fn contains_section(self, section: u32) -> bool {
+        (self.start..=self.end).contains(&section)
+    }
+
+    /// Whether every section of `other` is in this range too.
+    pub fn contains(self, other: SectionRange) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    /// Whether any section is in both ranges, from the endpoints alone.
+    pub fn overlaps(self, other: SectionRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// The sections in both ranges, if there are any.
+    pub fn intersection(self, other: SectionRange) -> Option<SectionRange> {
+        SectionRange::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The sections in either range, if that's a range itself, i.e. the two
+    /// overlap or one ends right before the other starts.
+    pub fn union(self, other: SectionRange) -> Option<SectionRange> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        (u64::from(second.start) <= u64::from(first.end) + 1)
+            .then(|| SectionRange::new(first.start, first.end.max(second.end)))
+            .flatten()
+    }
+}
+
+impl Display for SectionRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Why a range or assignment didn't parse, and where in the text given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Counting from 1, in characters.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// The same error in text that had `before` in front of what was parsed.
+    fn after(self, before: &str) -> Self {
+        ParseError::new(self.column + before.chars().count(), self.message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `2-4`.
+impl FromStr for SectionRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ids = [0; 2];
+        let mut fields = s.split('-');
+        let mut column = 1;
+        for id in &mut ids {
+            let field = fields.next().ok_or_else(|| {
+                ParseError::new(s.chars().count() + 1, "expected a range like `2-4`")
+            })?;
+            *id = field.parse().map_err(|err| {
+                ParseError::new(column, format!("`{field}` isn't a section id: {err}"))
+            })?;
+            column += field.chars().count() + 1;
+        }
+        if fields.next().is_some() {
+            return Err(ParseError::new(
+                column - 1,
+                "expected the range to end after two section ids",
+            ));
+        }
+
+        let [start, end] = ids;
+        SectionRange::new(start, end)
+            .ok_or_else(|| ParseError::new(1, format!("`{s}` runs backwards")))
+    }
+}
+
+/// The ranges of one pair of elves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Assignment {
+    pub first: SectionRange,
+    pub second: SectionRange,
+}
+
+impl Assignment {
+    /// Whether one elf's range is entirely within the other's.
+    pub fn fully_contained(self) -> bool {
+        self.first.contains(self.second) || self.second.contains(self.first)
+    }
+
+    pub fn overlapping(self) -> bool {
+        self.first.overlaps(self.second)
+    }
+}
+
+impl Display for Assignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.first, self.second)
+    }
+}
+
+/// Parses `2-4,6-8`.
+impl FromStr for Assignment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = s.split(',');
+        let first = ranges.next().unwrap_or_default();
+        let second = ranges.next();
+        let first_range: SectionRange = first.parse()?;
+        let Some(second) = second else {
+            return Err(ParseError::new(
+                s.chars().count() + 1,
+                "expected a pair of ranges like `2-4,6-8`",
+            ));
+        };
+        let second_range: SectionRange = second
+            .parse()
+            .map_err(|err: ParseError| err.after(&s[..=first.len()]))?;
+        if ranges.next().is_some() {
+            return Err(ParseError::new(
+                first.chars().count() + second.chars().count() + 2,
+                "expected the line to end after two ranges",
+            ));
+        }
+
+        Ok(Assignment {
+            first: first_range,
+            second: second_range,
+        })
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Parsed<'a> = Vec<Assignment>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<Assignment>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.parse()
+                    .map_err(|err: ParseError| Error::parse(4, idx + 1, err.column, err.message))
+            })
+            .collect()
+    }
+
+    fn part_one(pairs: &Vec<Assignment>) -> Result<usize, Error> {
+        Ok(count_contained(pairs))
+    }
+
+    fn part_two(pairs: &Vec<Assignment>) -> Result<usize, Error> {
+        Ok(count_overlapping(pairs))
+    }
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, Error> {
+    Ok(count_contained(&Day4::parse(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, Error> {
+    Ok(count_overlapping(&Day4::parse(input)?))
+}
+
+fn count_contained(pairs: &[Assignment]) -> usize {
+    pairs.iter().filter(|pair| pair.fully_contained()).count()
+}
+
+fn count_overlapping(pairs: &[Assignment]) -> usize {
+    pairs.iter().filter(|pair| pair.overlapping()).count()
+}
+
+#[cfg(test)]
+mod fixtures {
+    pub const EXAMPLE_INPUT: &str = include_str!("../../inputs/2022/day-4/example.txt");
+    pub const ACTUAL_INPUT: &str = include_str!("../../inputs/2022/day-4/actual.txt");
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+        solve_part_1, solve_part_2, Assignment, SectionRange,
+    };
+    use aoc_core::{answers::expected, Error, Part};
+    use proptest::prelude::*;
+
+    /// How overlaps used to be found, walking one range and checking each
+    /// section against the other.
+    fn overlaps_by_walking(first: SectionRange, second: SectionRange) -> bool {
+        let mut z = first.start()..=first.end();
+        let mut x = second.start()..=second.end();
+
+        let a = z.any(|i| x.contains(&i));
+        let b = x.any(|i| z.contains(&i));
+
+        a || b
+    }
+
+    fn section_range(max: u32) -> impl Strategy<Value = SectionRange> {
+        (0..=max, 0..=max).prop_map(|(a, b)| SectionRange::new(a.min(b), a.max(b)).unwrap())
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            solve_part_1(EXAMPLE_INPUT),
+            Ok(expected(4, "example", Part::One))
+        );
+        assert_eq!(
+            solve_part_1(ACTUAL_INPUT),
+            Ok(expected(4, "actual", Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            solve_part_2(EXAMPLE_INPUT),
+            Ok(expected(4, "example", Part::Two))
+        );
+        assert_eq!(
+            solve_part_2(ACTUAL_INPUT),
+            Ok(expected(4, "actual", Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let at = |line| match solve_part_1(&format!("2-4,6-8\n{line}")) {
+            Err(Error::Parse {
+                line: 2, column, ..
+            }) => column,
+            other => panic!("expected a parse error on line 2, got {other:?}"),
+        };
+
+        assert_eq!(at("2-4,x-8"), 5);
+        assert_eq!(at("2-4,6"), 6);
+        assert_eq!(at("2-4,6-8,1"), 8);
+        assert_eq!(at("\n1-2,3-4"), 1);
+        assert_eq!(at("2-4,6-8-9"), 8);
+        assert_eq!(at("24,6-8"), 3);
+        assert_eq!(at("2-4,8-6"), 5);
+    }
+
+    #[test]
+    fn test_section_range() {
+        let range = |s: &str| s.parse::<SectionRange>().unwrap();
+
+        assert_eq!(range("2-8").len(), 7);
+        assert_eq!(range("0-4294967295").len(), 1 << 32);
+        assert!(range("2-8").contains(range("3-7")));
+        assert!(range("2-8").contains(range("2-8")));
+        assert!(!range("3-7").contains(range("2-8")));
+
+        assert!(range("5-7").overlaps(range("7-9")));
+        assert!(range("7-9").overlaps(range("5-7")));
+        assert!(!range("2-4").overlaps(range("6-8")));
+
+        assert_eq!(range("2-6").intersection(range("4-8")), Some(range("4-6")));
+        assert_eq!(range("2-4").intersection(range("5-8")), None);
+        assert_eq!(range("4-8").union(range("2-6")), Some(range("2-8")));
+        assert_eq!(range("2-4").union(range("5-8")), Some(range("2-8")));
+        assert_eq!(range("2-4").union(range("6-8")), None);
+        assert_eq!(range("2-4").union(range("3-3")), Some(range("2-4")));
+
+        assert_eq!(SectionRange::new(4, 2), None);
+        assert_eq!(
+            "4-2".parse::<SectionRange>().unwrap_err().to_string(),
+            "column 1: `4-2` runs backwards"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_overlaps_like_walking(first in section_range(300), second in section_range(300)) {
+            prop_assert_eq!(first.overlaps(second), overlaps_by_walking(first, second));
+            prop_assert_eq!(first.overlaps(second), second.overlaps(first));
+            prop_assert_eq!(first.overlaps(second), first.intersection(second).is_some());
+        }
+    }
+
+    #[test]
+    fn test_overlaps_huge_ranges() {
+        let range = |s: &str| s.parse::<SectionRange>().unwrap();
+        assert!(range("0-4294967295").overlaps(range("4294967295-4294967295")));
+        assert!(range("1000000000-3000000000").overlaps(range("2999999999-4000000000")));
+        assert!(!range("0-3000000000").overlaps(range("3000000001-4294967295")));
+    }
+
+    #[test]
+    fn test_assignment() {
+        let pair: Assignment = "2-8,3-7".parse().unwrap();
+        assert_eq!(pair.to_string(), "2-8,3-7");
+        assert!(pair.fully_contained());
+        assert!(pair.overlapping());
+
+        let pair: Assignment = "2-3,4-5".parse().unwrap();
+        assert!(!pair.fully_contained());
+        assert!(!pair.overlapping());
+    }
+}
@@ -6,13 +6,14 @@ use nom::{
     bytes::complete::tag,
     character::complete::{digit1, space1},
     combinator::map_opt,
+    error::{Error, ErrorKind},
     sequence::{separated_pair, tuple},
     IResult,
 };
 
 #[allow(clippy::missing_errors_doc)]
 pub fn parse_instruction(i: &str) -> IResult<&str, Move> {
-    let (i, ((operation, qty), _, (_, from), _, (_, to))) = tuple((
+    let (rest, ((operation, qty), _, (_, from), _, (_, to))) = tuple((
         parse_specifier_numerator_pair,
         space1,
         parse_specifier_numerator_pair,
@@ -24,8 +25,9 @@ pub fn parse_instruction(i: &str) -> IResult<&str, Move> {
     // let ... = separated_list1(space1, parse_specifier_numerator_pair)(i)?;
 
     match operation {
-        "move" => Ok((i, Move { qty, from, to })),
-        _ => unimplemented!(),
+        "move" => Ok((rest, Move { qty, from, to })),
+        // Moving is all a crane does, blame the operation for anything else.
+        _ => Err(nom::Err::Error(Error::new(i, ErrorKind::Tag))),
     }
 }
 
@@ -76,6 +78,18 @@ mod tests {
         parse_instruction("asd 1 from 2 to 1").unwrap();
     }
 
+    #[test]
+    fn test_parse_unknown_operation() {
+        let err = parse_instruction("from 1 move 2 to 1").unwrap_err();
+        assert_eq!(
+            err,
+            nom::Err::Error(nom::error::Error::new(
+                "from 1 move 2 to 1",
+                nom::error::ErrorKind::Tag
+            ))
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_parse_no_seperators() {
@@ -0,0 +1,590 @@
+use crate::{instruction_parser::parse_instruction, stacks_parser::parse_lines};
+use aoc_core::{error::position, Error, Solution};
+use std::{
+    fmt::{Debug, Display, Write},
+    ops::{Index, IndexMut},
+    thread::sleep,
+    time::Duration,
+};
+
+pub mod instruction_parser;
+pub mod stacks_parser;
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Crate(char);
+
+impl From<char> for Crate {
+    fn from(value: char) -> Self {
+        Crate(value)
+    }
+}
+
+impl Display for Crate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char('[')?;
+        f.write_char(self.0)?;
+        f.write_char(']')?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct Stacks {
+    inner: Vec<Vec<Crate>>,
+}
+
+impl Stacks {
+    fn height(&self) -> usize {
+        self.inner.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    fn width(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn pop_from_column(&mut self, column: usize) -> Option<Crate> {
+        self.inner.index_mut(column).pop()
+    }
+
+    fn push_to_column(&mut self, column: usize, element: Crate) {
+        self.inner.index_mut(column).push(element);
+    }
+
+    fn index_column(&self, column: usize, row: usize) -> Option<&Crate> {
+        self.inner.index(column).get(row)
+    }
+
+    /// The 0-based columns `moove` takes crates from and puts them on.
+    fn columns(&self, moove: &Move) -> Result<(usize, usize), String> {
+        let column = |stack: usize| {
+            (1..=self.width())
+                .contains(&stack)
+                .then(|| stack - 1)
+                .ok_or(format!(
+                    "there's no stack {stack}, only 1 to {}",
+                    self.width()
+                ))
+        };
+
+        Ok((column(moove.from)?, column(moove.to)?))
+    }
+
+    fn get_message(&self) -> Option<String> {
+        self.inner
+            .iter()
+            // An empty stack leaves a gap, so there's no message to read.
+            .map(|col| col.last().map(|krate| krate.0))
+            .collect()
+    }
+
+    fn parse(input: &str) -> Result<Stacks, Error> {
+        // The last line, this should be stack indices, we can be remove this and use it to assert parse correctness.
+        let (to_stack, stack_indices) = input.rsplit_once('\n').ok_or_else(|| {
+            Error::parse(5, 1, 1, "expected rows of crates above the stack numbers")
+        })?;
+        let suposed_stack_count: usize = stack_indices.chars().filter(char::is_ascii_digit).count();
+
+        // Parse the stack minus the indices.
+        let parsed = match parse_lines(to_stack.as_bytes()) {
+            Ok((b"", rows)) => Ok(rows),
+            Ok((rest, _)) => Err(rest),
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => Err(err.input),
+            Err(nom::Err::Incomplete(_)) => Err(&b""[..]),
+        };
+        let rows = parsed.map_err(|rest| {
+            let (line, column) = byte_position(to_stack, rest);
+            Error::parse(
+                5,
+                line,
+                column,
+                "expected a crate like `[A]` or three spaces",
+            )
+        })?;
+
+        let width = rows[0].len();
+        if let Some(idx) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::parse(
+                5,
+                idx + 1,
+                1,
+                format!(
+                    "expected {width} slots like the first row, found {}",
+                    rows[idx].len()
+                ),
+            ));
+        }
+        let stack_rows = transpose(rows);
+
+        // Check that indices match the width of our parsed crates.
+        if stack_rows.len() != suposed_stack_count {
+            return Err(Error::parse(
+                5,
+                to_stack.lines().count() + 1,
+                1,
+                format!(
+                    "{suposed_stack_count} stacks are numbered but there are {} columns of crates",
+                    stack_rows.len()
+                ),
+            ));
+        }
+
+        // Every stack exists up front, so one with no crates keeps its place.
+        let mut stacks = Stacks {
+            inner: vec![Vec::new(); stack_rows.len()],
+        };
+        for (column_idx, column) in stack_rows.iter().enumerate() {
+            let column_items = column
+                .iter()
+                // We want to discard empty crates, if we ignore empty crates we don't have
+                // to deal with them after parsing.
+                .flatten()
+                .rev();
+
+            for krate in column_items {
+                stacks.push_to_column(column_idx, (*krate).into());
+            }
+        }
+
+        Ok(stacks)
+    }
+}
+
+impl Display for Stacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Render crates.
+        for row in (0..=self.height()).rev() {
+            for column in 0..self.width() {
+                if let Some(krate) = self.index_column(column, row) {
+                    f.write_fmt(format_args!("{krate}"))?;
+                } else {
+                    f.write_str("   ")?;
+                }
+                if column != self.width() - 1 {
+                    f.write_char(' ')?;
+                }
+            }
+            f.write_char('\n')?;
+        }
+
+        // Label crate columns.
+        for column in 0..self.width() {
+            f.write_char(' ')?;
+            f.write_str((column + 1).to_string().as_str())?;
+            f.write_char(' ')?;
+            if column != self.width() - 1 {
+                f.write_char(' ')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    qty: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Move {qty} from {from} to {to}",
+            qty = self.qty,
+            from = self.from,
+            to = self.to,
+        )
+    }
+}
+
+fn out_of_crates(moove: &Move) -> String {
+    format!("stack {} runs out of crates", moove.from)
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn enact_move_part_1(stacks: &mut Stacks, moove: &Move) -> Result<(), String> {
+    let (from, to) = stacks.columns(moove)?;
+    for _ in 1..=moove.qty {
+        let krate = stacks
+            .pop_from_column(from)
+            .ok_or_else(|| out_of_crates(moove))?;
+        stacks.push_to_column(to, krate);
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn enact_move_part_2(stacks: &mut Stacks, moove: &Move) -> Result<(), String> {
+    let (from, to) = stacks.columns(moove)?;
+    let picked_up: Vec<Crate> = (1..=moove.qty)
+        .map(|_| {
+            stacks
+                .pop_from_column(from)
+                .ok_or_else(|| out_of_crates(moove))
+        })
+        .collect::<Result<_, _>>()?;
+
+    for krate in picked_up.into_iter().rev() {
+        stacks.push_to_column(to, krate);
+    }
+
+    Ok(())
+}
+
+/// Parses the move on `line` of the input, pointing any error at where in
+/// the line it went wrong.
+fn parse_move(line: usize, instruction: &str) -> Result<Move, Error> {
+    let rest = match parse_instruction(instruction) {
+        Ok(("", moove)) => return Ok(moove),
+        Ok((rest, _)) => rest,
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+        Err(nom::Err::Incomplete(_)) => "",
+    };
+
+    let (_, column) = position(instruction, rest);
+    Err(Error::parse(
+        5,
+        line,
+        column,
+        "expected a move like `move 1 from 2 to 1`",
+    ))
+}
+
+/// [`position`] for a parser that worked on the bytes of `input`, which can
+/// give up halfway through a character.
+fn byte_position(input: &str, rest: &[u8]) -> (usize, usize) {
+    let mut offset = input.len() - rest.len();
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    position(input, &input[offset..])
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    /// The stacks and every move along with the line it's on.
+    type Parsed<'a> = (Stacks, Vec<(usize, Move)>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<(Stacks, Vec<(usize, Move)>), Error> {
+        let (raw_stacks, raw_instructions) = input.split_once("\n\n").ok_or_else(|| {
+            let (line, column) = position(input, "");
+            Error::parse(
+                5,
+                line,
+                column,
+                "expected a blank line between the stacks and the moves",
+            )
+        })?;
+        let stacks = Stacks::parse(raw_stacks)?;
+
+        // Moves start after the stacks and the blank line.
+        let first_line = raw_stacks.lines().count() + 2;
+        let moves = raw_instructions
+            .lines()
+            .zip(first_line..)
+            .map(|(instruction, line)| Ok((line, parse_move(line, instruction)?)))
+            .collect::<Result<_, Error>>()?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part_one((stacks, moves): &(Stacks, Vec<(usize, Move)>)) -> Result<String, Error> {
+        rearrange(stacks, moves, enact_move_part_1)?
+            .get_message()
+            .ok_or(Error::NoAnswer {
+                day: 5,
+                part: aoc_core::Part::One,
+            })
+    }
+
+    fn part_two((stacks, moves): &(Stacks, Vec<(usize, Move)>)) -> Result<String, Error> {
+        rearrange(stacks, moves, enact_move_part_2)?
+            .get_message()
+            .ok_or(Error::NoAnswer {
+                day: 5,
+                part: aoc_core::Part::Two,
+            })
+    }
+}
+
+/// Applies every move to a copy of `stacks` without any of the animation.
+fn rearrange<F>(stacks: &Stacks, moves: &[(usize, Move)], solver: F) -> Result<Stacks, Error>
+where
+    F: Fn(&mut Stacks, &Move) -> Result<(), String>,
+{
+    let mut stacks = stacks.clone();
+    for (line, moove) in moves {
+        solver(&mut stacks, moove).map_err(|message| Error::Solve {
+            day: 5,
+            line: Some(*line),
+            message,
+        })?;
+    }
+
+    Ok(stacks)
+}
+
+pub enum Part {
+    One(Stage),
+    Two(Stage),
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One(stage) => write!(f, "One > {stage}:"),
+            Part::Two(stage) => write!(f, "Two > {stage}:"),
+        }
+    }
+}
+
+pub enum Stage {
+    Example,
+    Actual,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Example => write!(f, "Example"),
+            Stage::Actual => write!(f, "Actual"),
+        }
+    }
+}
+
+#[allow(clippy::missing_errors_doc)]
+pub fn solve_animated<F>(
+    part: &Part,
+    solver: F,
+    input: &str,
+    animation_duration: u64,
+) -> Result<String, Error>
+where
+    F: Fn(&mut Stacks, &Move) -> Result<(), String>,
+{
+    if animation_duration > 0 {
+        for _ in 0..10 {
+            print!("{}[2J", 27 as char);
+        }
+    }
+
+    let (mut stacks, moves) = Day5::parse(input)?;
+
+    #[cfg(debug_assertions)]
+    {
+        println!("Initial stack:");
+        println!("{stacks}");
+    }
+
+    if animation_duration > 0 {
+        // CBA using crossterm lol.
+        print!("{}", "\n".repeat(100));
+        println!("{stacks}");
+    }
+
+    for (line, moove) in moves {
+        if animation_duration > 0 {
+            sleep(Duration::from_millis(animation_duration));
+            print!("{}", "\n".repeat(100));
+            print!("{}[2J", 27 as char);
+        }
+        #[cfg(debug_assertions)]
+        {
+            println!("\nApplied `{moove}`:");
+        }
+
+        solver(&mut stacks, &moove).map_err(|message| Error::Solve {
+            day: 5,
+            line: Some(line),
+            message,
+        })?;
+
+        #[cfg(debug_assertions)]
+        {
+            println!("{stacks}");
+        }
+        if animation_duration > 0 {
+            println!("{stacks}");
+            println!("Applied `{moove}`.");
+        }
+    }
+
+    let result = stacks.get_message().ok_or(Error::NoAnswer {
+        day: 5,
+        part: match part {
+            Part::One(_) => aoc_core::Part::One,
+            Part::Two(_) => aoc_core::Part::Two,
+        },
+    })?;
+
+    println!("Solved {part} {result}");
+    if animation_duration < 1 {
+        return Ok(result);
+    }
+
+    sleep(Duration::from_millis(2000));
+
+    Ok(result)
+}
+
+// https://stackoverflow.com/a/64499219
+fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
+    assert!(!v.is_empty());
+    let len = v[0].len();
+    let mut iters: Vec<_> = v
+        .into_iter()
+        .map(std::iter::IntoIterator::into_iter)
+        .collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .map(|n| n.next().unwrap())
+                .collect::<Vec<T>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod fixtures {
+    pub const EXAMPLE_INPUT: &str = include_str!("../../inputs/2022/day-5/example.txt");
+    pub const ACTUAL_INPUT: &str = include_str!("../../inputs/2022/day-5/actual.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        enact_move_part_1, enact_move_part_2,
+        fixtures::{ACTUAL_INPUT, EXAMPLE_INPUT},
+        solve_animated, Day5, Part, Stage,
+    };
+    use aoc_core::{answers::expected, Error, Solution};
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            solve_animated(
+                &Part::One(Stage::Example),
+                enact_move_part_1,
+                EXAMPLE_INPUT,
+                0
+            ),
+            Ok(expected::<String>(5, "example", aoc_core::Part::One))
+        );
+        assert_eq!(
+            solve_animated(
+                &Part::One(Stage::Actual),
+                enact_move_part_1,
+                ACTUAL_INPUT,
+                0
+            ),
+            Ok(expected::<String>(5, "actual", aoc_core::Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            solve_animated(
+                &Part::Two(Stage::Example),
+                enact_move_part_2,
+                EXAMPLE_INPUT,
+                0
+            ),
+            Ok(expected::<String>(5, "example", aoc_core::Part::Two))
+        );
+        assert_eq!(
+            solve_animated(
+                &Part::Two(Stage::Actual),
+                enact_move_part_2,
+                ACTUAL_INPUT,
+                0
+            ),
+            Ok(expected::<String>(5, "actual", aoc_core::Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let parse_error = |input: &str| match Day5::parse(input) {
+            Err(Error::Parse { line, column, .. }) => (line, column),
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        };
+
+        assert_eq!(
+            parse_error("[A] [B]\n 1   2   3 \n\nmove 1 from 1 to 2"),
+            (2, 1)
+        );
+        assert_eq!(parse_error("[A] [B]\n 1   2 "), (2, 8));
+        assert_eq!(parse_error("[A] [B]\n[C} [D]\n 1   2 \n\n"), (2, 1));
+        assert_eq!(
+            parse_error("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nlift 1 from 1 to 2"),
+            (5, 1)
+        );
+        assert_eq!(
+            parse_error("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2 twice"),
+            (4, 19)
+        );
+
+        let solved = |input: &str| Day5::part_one(&Day5::parse(input).unwrap());
+        assert_eq!(
+            solved("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 1 to 2"),
+            Err(Error::Solve {
+                day: 5,
+                line: Some(5),
+                message: "stack 1 runs out of crates".to_owned()
+            })
+        );
+        assert_eq!(
+            solved("[A] [B]\n 1   2 \n\nmove 1 from 1 to 3"),
+            Err(Error::Solve {
+                day: 5,
+                line: Some(4),
+                message: "there's no stack 3, only 1 to 2".to_owned()
+            })
+        );
+        assert_eq!(
+            solved("[A] [B]\n 1   2 \n\nmove 1 from 1 to 2"),
+            Err(Error::NoAnswer {
+                day: 5,
+                part: aoc_core::Part::One
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_stacks() {
+        let solved = |input: &str| {
+            let parsed = Day5::parse(input).unwrap();
+            (Day5::part_one(&parsed), Day5::part_two(&parsed))
+        };
+        let ok = |message: &str| (Ok(message.to_owned()), Ok(message.to_owned()));
+
+        // First, middle and last stacks starting empty.
+        assert_eq!(
+            solved("    [A]\n    [B]\n 1   2 \n\nmove 1 from 2 to 1\n"),
+            ok("AB")
+        );
+        assert_eq!(
+            solved("[A]     [B]\n[C]     [D]\n 1   2   3 \n\nmove 1 from 1 to 2\n"),
+            ok("CAB")
+        );
+        assert_eq!(
+            solved("[A]    \n[B]    \n 1   2 \n\nmove 1 from 1 to 2\n"),
+            ok("BA")
+        );
+
+        let (stacks, _) = Day5::parse("       \n 1   2 \n\n").unwrap();
+        assert_eq!(stacks.height(), 0);
+        assert_eq!(stacks.to_string(), "       \n 1   2 ");
+    }
+}
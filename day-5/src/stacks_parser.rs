@@ -45,7 +45,7 @@ pub fn parse_lines(i: &[u8]) -> IResult<&[u8], Vec<Vec<Option<char>>>> {
 #[cfg(test)]
 mod tests {
     use super::{parse_crate, parse_empty_crate, parse_line, parse_lines};
-    use crate::EXAMPLE_INPUT;
+    use crate::fixtures::EXAMPLE_INPUT;
 
     #[test]
     fn test_parse_valid_line() {
@@ -0,0 +1,159 @@
+use aoc_core::{Error, Part, Solution};
+use std::collections::HashMap;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a str;
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Result<&str, Error> {
+        // A downloaded input ends in a newline, which isn't part of the signal.
+        Ok(input.trim_end_matches(['\n', '\r']))
+    }
+
+    fn part_one(input: &&str) -> Result<isize, Error> {
+        yuck_solve(input, 4).ok_or(Error::NoAnswer {
+            day: 6,
+            part: Part::One,
+        })
+    }
+
+    fn part_two(input: &&str) -> Result<isize, Error> {
+        yuck_solve(input, 14).ok_or(Error::NoAnswer {
+            day: 6,
+            part: Part::Two,
+        })
+    }
+}
+
+pub fn yuck_solve(input: &str, window_size: isize) -> Option<isize> {
+    let mut window: HashMap<char, isize> = HashMap::new();
+
+    for (idx, char) in input.chars().enumerate() {
+        // Because I suck:
+        let idx = idx as isize;
+
+        // Remove the oldest member once the window is full.
+        let pred = idx - window_size;
+        window.retain(|_, v| *v != pred);
+
+        // Check if we're ready to complete and just return the next idx.
+        if window.len() == (window_size as usize) - 1 && !window.contains_key(&char) {
+            return Some(idx + 1);
+        }
+
+        // Insert into our window updating the idx if duplicitous.
+        window.insert(char, idx);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod fixtures {
+    pub const EXAMPLE_1: &str = include_str!("../../inputs/2022/day-6/example-1.txt");
+    pub const EXAMPLE_2: &str = include_str!("../../inputs/2022/day-6/example-2.txt");
+    pub const EXAMPLE_3: &str = include_str!("../../inputs/2022/day-6/example-3.txt");
+    pub const EXAMPLE_4: &str = include_str!("../../inputs/2022/day-6/example-4.txt");
+    pub const EXAMPLE_5: &str = include_str!("../../inputs/2022/day-6/example-5.txt");
+    pub const EXAMPLE_6: &str = include_str!("../../inputs/2022/day-6/example-6.txt");
+    pub const EXAMPLE_7: &str = include_str!("../../inputs/2022/day-6/example-7.txt");
+    pub const EXAMPLE_8: &str = include_str!("../../inputs/2022/day-6/example-8.txt");
+    pub const EXAMPLE_9: &str = include_str!("../../inputs/2022/day-6/example-9.txt");
+    pub const ACTUAL: &str = include_str!("../../inputs/2022/day-6/actual.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        fixtures::{
+            ACTUAL, EXAMPLE_1, EXAMPLE_2, EXAMPLE_3, EXAMPLE_4, EXAMPLE_5, EXAMPLE_6, EXAMPLE_7,
+            EXAMPLE_8, EXAMPLE_9,
+        },
+        yuck_solve, Day6,
+    };
+    use aoc_core::{answers::expected, Error, Part, Solution};
+
+    #[test]
+    fn test_part_one_examples() {
+        assert_eq!(
+            yuck_solve(EXAMPLE_1, 4),
+            Some(expected(6, "example-1", Part::One))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_2, 4),
+            Some(expected(6, "example-2", Part::One))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_3, 4),
+            Some(expected(6, "example-3", Part::One))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_4, 4),
+            Some(expected(6, "example-4", Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_one_actual() {
+        assert_eq!(
+            yuck_solve(ACTUAL, 4),
+            Some(expected(6, "actual", Part::One))
+        );
+    }
+
+    #[test]
+    fn test_part_two_examples() {
+        assert_eq!(
+            yuck_solve(EXAMPLE_5, 14),
+            Some(expected(6, "example-5", Part::Two))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_6, 14),
+            Some(expected(6, "example-6", Part::Two))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_7, 14),
+            Some(expected(6, "example-7", Part::Two))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_8, 14),
+            Some(expected(6, "example-8", Part::Two))
+        );
+        assert_eq!(
+            yuck_solve(EXAMPLE_9, 14),
+            Some(expected(6, "example-9", Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_part_two_actual() {
+        assert_eq!(
+            yuck_solve(ACTUAL, 14),
+            Some(expected(6, "actual", Part::Two))
+        );
+    }
+
+    #[test]
+    fn test_newline_terminated() {
+        let solved = |input: &str| {
+            let signal = Day6::parse(input).unwrap();
+            (Day6::part_one(&signal), Day6::part_two(&signal))
+        };
+
+        for input in [EXAMPLE_1, EXAMPLE_5, ACTUAL] {
+            assert_eq!(solved(&format!("{input}\n")), solved(input));
+            assert_eq!(solved(&format!("{input}\r\n")), solved(input));
+        }
+        assert_eq!(
+            solved("abc\n").0,
+            Err(Error::NoAnswer {
+                day: 6,
+                part: Part::One
+            })
+        );
+    }
+}